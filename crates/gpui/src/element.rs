@@ -265,7 +265,7 @@ impl<C: RenderOnce> IntoElement for Component<C> {
 }
 
 /// A globally unique identifier for an element, used to track state across frames.
-#[derive(Deref, DerefMut, Default, Debug, Eq, PartialEq, Hash)]
+#[derive(Deref, DerefMut, Clone, Default, Debug, Eq, PartialEq, Hash)]
 pub struct GlobalElementId(pub(crate) SmallVec<[ElementId; 32]>);
 
 impl Display for GlobalElementId {
@@ -410,6 +410,7 @@ impl<E: Element> Drawable<E> {
 
                 let bounds = window.layout_bounds(layout_id);
                 let node_id = window.next_frame.dispatch_tree.push_node();
+                let first_hitbox_index = window.next_frame.hitboxes.len();
                 let prepaint = self.element.prepaint(
                     global_id.as_ref(),
                     inspector_id.as_ref(),
@@ -418,6 +419,7 @@ impl<E: Element> Drawable<E> {
                     window,
                     cx,
                 );
+                window.end_hitbox_subtree(first_hitbox_index);
                 window.next_frame.dispatch_tree.pop_node();
 
                 if global_id.is_some() {
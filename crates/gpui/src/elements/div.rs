@@ -16,13 +16,13 @@
 //! constructed by combining these two systems into an all-in-one element.
 
 use crate::{
-    Action, AnyDrag, AnyElement, AnyTooltip, AnyView, App, Bounds, ClickEvent, DispatchPhase,
-    Element, ElementId, Entity, FocusHandle, Global, GlobalElementId, Hitbox, HitboxBehavior,
-    HitboxId, InspectorElementId, IntoElement, IsZero, KeyContext, KeyDownEvent, KeyUpEvent,
-    LayoutId, ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
-    Overflow, ParentElement, Pixels, Point, Render, ScrollWheelEvent, SharedString, Size, Style,
-    StyleRefinement, Styled, Task, TooltipId, Visibility, Window, WindowControlArea, point, px,
-    size,
+    Action, AnyDrag, AnyElement, AnyTooltip, AnyView, App, Bounds, ClickEvent, ContentMask,
+    DispatchPhase, Element, ElementId, Entity, FocusHandle, Global, GlobalElementId, Hitbox,
+    HitboxBehavior, HitboxId, InspectorElementId, IntoElement, IsZero, KeyContext, KeyDownEvent,
+    KeyUpEvent, LayoutId, ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseExitEvent,
+    MouseMoveEvent, MouseUpEvent, Overflow, ParentElement, Pixels, Point, Render,
+    ScrollWheelEvent, SharedString, Size, Style, StyleRefinement, Styled, Task, TooltipId,
+    Visibility, Window, WindowControlArea, point, px, size,
 };
 use collections::HashMap;
 use refineable::Refineable;
@@ -36,15 +36,18 @@ use std::{
     mem,
     rc::Rc,
     sync::Arc,
-    time::Duration,
+    time::{Duration, Instant},
 };
 use util::ResultExt;
 
 use super::ImageCacheProvider;
 
 const DRAG_THRESHOLD: f64 = 2.;
+const CLICK_CANCEL_THRESHOLD: f64 = 4.;
 const TOOLTIP_SHOW_DELAY: Duration = Duration::from_millis(500);
 const HOVERABLE_TOOLTIP_HIDE_DELAY: Duration = Duration::from_millis(500);
+const DRAG_AUTOSCROLL_EDGE: Pixels = px(24.);
+const DRAG_AUTOSCROLL_MAX_STEP: Pixels = px(12.);
 
 /// The styling information for a given group.
 pub struct GroupStyle {
@@ -247,7 +250,7 @@ impl Interactivity {
             .push(Box::new(move |event, phase, hitbox, window, cx| {
                 if phase == DispatchPhase::Capture
                     && event.button == button
-                    && !hitbox.is_hovered(window)
+                    && !hitbox.is_hovered_ignoring_capture(window)
                 {
                     (listener)(event, window, cx);
                 }
@@ -642,13 +645,29 @@ pub trait InteractiveElement: Sized {
         self
     }
 
-    /// Apply the given style to this element when the mouse hovers over a group member
+    /// Apply the given style to this element when the mouse hovers over a group member.
+    /// Can be called multiple times with different groups, each style is applied in registration order.
     fn group_hover(
         mut self,
         group_name: impl Into<SharedString>,
         f: impl FnOnce(StyleRefinement) -> StyleRefinement,
     ) -> Self {
-        self.interactivity().group_hover_style = Some(GroupStyle {
+        self.interactivity().group_hover_styles.push(GroupStyle {
+            group: group_name.into(),
+            style: Box::new(f(StyleRefinement::default())),
+        });
+        self
+    }
+
+    /// Apply the given style to this element when focus is within the given group. The group
+    /// element needs an id or a focus handle from `track_focus`.
+    /// Can be called multiple times with different groups, each style is applied in registration order.
+    fn group_focus(
+        mut self,
+        group_name: impl Into<SharedString>,
+        f: impl FnOnce(StyleRefinement) -> StyleRefinement,
+    ) -> Self {
+        self.interactivity().group_focus_styles.push(GroupStyle {
             group: group_name.into(),
             style: Box::new(f(StyleRefinement::default())),
         });
@@ -1016,12 +1035,45 @@ pub trait StatefulInteractiveElement: InteractiveElement {
         self
     }
 
+    /// Scroll this element while something is dragged near its scrollable edges, faster the
+    /// closer the pointer gets to the edge.
+    fn autoscroll_on_drag(mut self) -> Self {
+        self.interactivity().drag_autoscroll = true;
+        self
+    }
+
+    /// Scroll this element with the arrow, page and home/end keys while it is focused. Keys bound
+    /// to actions in the focused context still run those actions instead.
+    fn keyboard_scroll(mut self) -> Self {
+        self.interactivity().keyboard_scroll = true;
+        self
+    }
+
     /// Track the scroll state of this element with the given handle.
     fn anchor_scroll(mut self, scroll_anchor: Option<ScrollAnchor>) -> Self {
         self.interactivity().scroll_anchor = scroll_anchor;
         self
     }
 
+    /// Apply the given style to this element once the mouse has hovered over it for `delay`.
+    /// The style is removed as soon as the mouse leaves, and entering again restarts the delay.
+    fn hover_delayed(
+        mut self,
+        delay: Duration,
+        f: impl FnOnce(StyleRefinement) -> StyleRefinement,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        debug_assert!(
+            self.interactivity().delayed_hover_style.is_none(),
+            "delayed hover style already set"
+        );
+        self.interactivity().delayed_hover_style =
+            Some((delay, Box::new(f(StyleRefinement::default()))));
+        self
+    }
+
     /// Set the given styles to be applied when this element is active.
     fn active(mut self, f: impl FnOnce(StyleRefinement) -> StyleRefinement) -> Self
     where
@@ -1040,7 +1092,7 @@ pub trait StatefulInteractiveElement: InteractiveElement {
     where
         Self: Sized,
     {
-        self.interactivity().group_active_style = Some(GroupStyle {
+        self.interactivity().group_active_styles.push(GroupStyle {
             group: group_name.into(),
             style: Box::new(f(StyleRefinement::default())),
         });
@@ -1129,6 +1181,46 @@ pub trait FocusableElement: InteractiveElement {
         self
     }
 
+    /// Include this element in Tab navigation. Elements are visited in ascending index order,
+    /// and elements with a negative index can only be focused programmatically or by clicking.
+    fn tab_index(mut self, index: isize) -> Self
+    where
+        Self: Sized,
+    {
+        self.interactivity().tab_index = Some(index);
+        self
+    }
+
+    /// Focus this element on the first frame it is painted. Later renders leave focus alone.
+    fn focus_on_mount(mut self) -> Self
+    where
+        Self: Sized,
+    {
+        self.interactivity().focus_on_mount = true;
+        self
+    }
+
+    /// Keep Tab navigation within this element's tab stops while it contains focus, as modals do.
+    /// Focus moved outside is sent back in, and focus returns to where it was before once this
+    /// element unmounts.
+    fn trap_focus(mut self) -> Self
+    where
+        Self: Sized,
+    {
+        self.interactivity().trap_focus = true;
+        self
+    }
+
+    /// Set the given styles to be applied when this element is focused by keyboard navigation
+    /// or programmatically, but not when it was focused by clicking it.
+    fn focus_visible(mut self, f: impl FnOnce(StyleRefinement) -> StyleRefinement) -> Self
+    where
+        Self: Sized,
+    {
+        self.interactivity().focus_visible_style = Some(Box::new(f(StyleRefinement::default())));
+        self
+    }
+
     /// Set the given styles to be applied when this element is inside another element that is focused.
     fn in_focus(mut self, f: impl FnOnce(StyleRefinement) -> StyleRefinement) -> Self
     where
@@ -1429,19 +1521,28 @@ pub struct Interactivity {
     pub(crate) key_context: Option<KeyContext>,
     pub(crate) focusable: bool,
     pub(crate) tracked_focus_handle: Option<FocusHandle>,
+    pub(crate) group_focus_handle: Option<FocusHandle>,
     pub(crate) tracked_scroll_handle: Option<ScrollHandle>,
     pub(crate) scroll_anchor: Option<ScrollAnchor>,
     pub(crate) scroll_offset: Option<Rc<RefCell<Point<Pixels>>>>,
+    pub(crate) drag_autoscroll: bool,
+    pub(crate) keyboard_scroll: bool,
     pub(crate) group: Option<SharedString>,
     /// The base style of the element, before any modifications are applied
     /// by focus, active, etc.
     pub base_style: Box<StyleRefinement>,
     pub(crate) focus_style: Option<Box<StyleRefinement>>,
+    pub(crate) focus_visible_style: Option<Box<StyleRefinement>>,
+    pub(crate) tab_index: Option<isize>,
+    pub(crate) trap_focus: bool,
+    pub(crate) focus_on_mount: bool,
     pub(crate) in_focus_style: Option<Box<StyleRefinement>>,
     pub(crate) hover_style: Option<Box<StyleRefinement>>,
-    pub(crate) group_hover_style: Option<GroupStyle>,
+    pub(crate) delayed_hover_style: Option<(Duration, Box<StyleRefinement>)>,
+    pub(crate) group_hover_styles: Vec<GroupStyle>,
+    pub(crate) group_focus_styles: Vec<GroupStyle>,
     pub(crate) active_style: Option<Box<StyleRefinement>>,
-    pub(crate) group_active_style: Option<GroupStyle>,
+    pub(crate) group_active_styles: Vec<GroupStyle>,
     pub(crate) drag_over_styles: Vec<(
         TypeId,
         Box<dyn Fn(&dyn Any, &mut Window, &mut App) -> StyleRefinement>,
@@ -1531,6 +1632,19 @@ impl Interactivity {
                     }
                 }
 
+                // Groups that don't take focus still need a handle in the dispatch tree, so that
+                // `group_focus` can tell whether the focused element is inside them.
+                if self.group.is_some() && self.tracked_focus_handle.is_none() {
+                    if let Some(element_state) = element_state.as_mut() {
+                        self.group_focus_handle = Some(
+                            element_state
+                                .focus_handle
+                                .get_or_insert_with(|| cx.focus_handle())
+                                .clone(),
+                        );
+                    }
+                }
+
                 if let Some(scroll_handle) = self.tracked_scroll_handle.as_ref() {
                     self.scroll_offset = Some(scroll_handle.0.borrow().offset.clone());
                 } else if self.base_style.overflow.x == Some(Overflow::Scroll)
@@ -1578,7 +1692,11 @@ impl Interactivity {
             },
         );
 
-        if let Some(focus_handle) = self.tracked_focus_handle.as_ref() {
+        if let Some(focus_handle) = self
+            .tracked_focus_handle
+            .as_ref()
+            .or(self.group_focus_handle.as_ref())
+        {
             window.set_focus_handle(focus_handle, cx);
         }
         window.with_optional_element_state::<InteractiveElementState, _>(
@@ -1603,22 +1721,28 @@ impl Interactivity {
                     }
                 }
 
+                // Hidden elements are laid out but never painted, so keep them and their
+                // descendants out of hit testing with an empty content mask.
+                let visible = style.visibility == Visibility::Visible;
+                let content_mask = if visible {
+                    style.overflow_mask(bounds, window.rem_size())
+                } else {
+                    Some(ContentMask {
+                        bounds: Bounds::new(bounds.origin, Size::default()),
+                    })
+                };
                 window.with_text_style(style.text_style().cloned(), |window| {
-                    window.with_content_mask(
-                        style.overflow_mask(bounds, window.rem_size()),
-                        |window| {
-                            let hitbox = if self.should_insert_hitbox(&style, window, cx) {
-                                Some(window.insert_hitbox(bounds, self.hitbox_behavior))
-                            } else {
-                                None
-                            };
+                    window.with_content_mask(content_mask, |window| {
+                        let hitbox = if visible && self.should_insert_hitbox(&style, window, cx) {
+                            Some(window.insert_hitbox(bounds, self.hitbox_behavior))
+                        } else {
+                            None
+                        };
 
-                            let scroll_offset =
-                                self.clamp_scroll_position(bounds, &style, window, cx);
-                            let result = f(&style, scroll_offset, hitbox, window, cx);
-                            (result, element_state)
-                        },
-                    )
+                        let scroll_offset = self.clamp_scroll_position(bounds, &style, window, cx);
+                        let result = f(&style, scroll_offset, hitbox, window, cx);
+                        (result, element_state)
+                    })
                 })
             },
         )
@@ -1632,7 +1756,8 @@ impl Interactivity {
             || self.scroll_offset.is_some()
             || self.tracked_focus_handle.is_some()
             || self.hover_style.is_some()
-            || self.group_hover_style.is_some()
+            || self.delayed_hover_style.is_some()
+            || !self.group_hover_styles.is_empty()
             || self.hover_listener.is_some()
             || !self.mouse_up_listeners.is_empty()
             || !self.mouse_down_listeners.is_empty()
@@ -1650,7 +1775,7 @@ impl Interactivity {
         bounds: Bounds<Pixels>,
         style: &Style,
         window: &mut Window,
-        _cx: &mut App,
+        cx: &mut App,
     ) -> Point<Pixels> {
         if let Some(scroll_offset) = self.scroll_offset.as_ref() {
             let mut scroll_to_bottom = false;
@@ -1660,14 +1785,24 @@ impl Interactivity {
                 .map(|handle| handle.0.borrow_mut());
             if let Some(mut scroll_handle_state) = tracked_scroll_handle.as_deref_mut() {
                 scroll_handle_state.overflow = style.overflow;
-                scroll_to_bottom = mem::take(&mut scroll_handle_state.scroll_to_bottom);
+                if let Some(animation) = scroll_handle_state.animation.as_ref() {
+                    let (offset, finished) = animation.offset_at(Instant::now());
+                    *scroll_offset.borrow_mut() = offset;
+                    if finished {
+                        scroll_handle_state.animation = None;
+                    } else {
+                        window.request_animation_frame();
+                    }
+                }
+                // Compare against the previous frame's maximum, so that newly appended content
+                // keeps a tail-following handle pinned, while scrolling up releases it.
+                let pinned_to_bottom =
+                    scroll_offset.borrow().y <= -scroll_handle_state.scroll_max.height;
+                scroll_to_bottom = mem::take(&mut scroll_handle_state.scroll_to_bottom)
+                    || (scroll_handle_state.follow_tail && pinned_to_bottom);
             }
 
-            let rem_size = window.rem_size();
-            let padding = style.padding.to_pixels(bounds.size.into(), rem_size);
-            let padding_size = size(padding.left + padding.right, padding.top + padding.bottom);
-            let padded_content_size = self.content_size + padding_size;
-            let scroll_max = (padded_content_size - bounds.size).max(&Size::default());
+            let (padded_content_size, scroll_max) = self.scroll_extent(bounds, style, window);
             // Clamp scroll offset in case scroll max is smaller now (e.g., if children
             // were removed or the bounds became larger).
             let mut scroll_offset = scroll_offset.borrow_mut();
@@ -1679,8 +1814,24 @@ impl Interactivity {
                 scroll_offset.y = scroll_offset.y.clamp(-scroll_max.height, px(0.));
             }
 
+            if self.drag_autoscroll && cx.has_active_drag() {
+                let step = drag_autoscroll_step(bounds, window.mouse_position(), style.overflow);
+                let scrolled = point(
+                    (scroll_offset.x + step.x).clamp(-scroll_max.width, px(0.)),
+                    (scroll_offset.y + step.y).clamp(-scroll_max.height, px(0.)),
+                );
+                if scrolled != *scroll_offset {
+                    *scroll_offset = scrolled;
+                    if let Some(scroll_handle_state) = tracked_scroll_handle.as_deref_mut() {
+                        scroll_handle_state.animation = None;
+                    }
+                    window.request_animation_frame();
+                }
+            }
+
             if let Some(mut scroll_handle_state) = tracked_scroll_handle {
                 scroll_handle_state.padded_content_size = padded_content_size;
+                scroll_handle_state.scroll_max = scroll_max;
             }
 
             *scroll_offset
@@ -1689,6 +1840,20 @@ impl Interactivity {
         }
     }
 
+    fn scroll_extent(
+        &self,
+        bounds: Bounds<Pixels>,
+        style: &Style,
+        window: &Window,
+    ) -> (Size<Pixels>, Size<Pixels>) {
+        let rem_size = window.rem_size();
+        let padding = style.padding.to_pixels(bounds.size.into(), rem_size);
+        let padding_size = size(padding.left + padding.right, padding.top + padding.bottom);
+        let padded_content_size = self.content_size + padding_size;
+        let scroll_max = (padded_content_size - bounds.size).max(&Size::default());
+        (padded_content_size, scroll_max)
+    }
+
     /// Paint this element according to this interactivity state's configured styles
     /// and bind the element's mouse and keyboard events.
     ///
@@ -1753,6 +1918,17 @@ impl Interactivity {
                                         }
 
                                         if let Some(group) = self.group.clone() {
+                                            if let Some(focus_handle) = self
+                                                .tracked_focus_handle
+                                                .clone()
+                                                .or_else(|| self.group_focus_handle.clone())
+                                            {
+                                                GroupFocusHandles::push(
+                                                    group.clone(),
+                                                    focus_handle,
+                                                    cx,
+                                                );
+                                            }
                                             GroupHitboxes::push(group, hitbox.id, cx);
                                         }
 
@@ -1762,14 +1938,35 @@ impl Interactivity {
                                         }
 
                                         self.paint_mouse_listeners(
+                                            global_id,
                                             hitbox,
                                             element_state.as_mut(),
                                             window,
                                             cx,
                                         );
                                         self.paint_scroll_listener(hitbox, &style, window, cx);
+                                        self.paint_keyboard_scroll_listener(hitbox, &style, window);
                                     }
 
+                                    if let Some((index, focus_handle)) =
+                                        self.tab_index.zip(self.tracked_focus_handle.as_ref())
+                                    {
+                                        window.insert_tab_stop(index, focus_handle);
+                                    }
+                                    if let Some(focus_handle) = self
+                                        .tracked_focus_handle
+                                        .as_ref()
+                                        .filter(|_| self.trap_focus)
+                                    {
+                                        window.insert_focus_trap(focus_handle);
+                                    }
+                                    if let Some(focus_handle) = self
+                                        .tracked_focus_handle
+                                        .as_ref()
+                                        .filter(|_| self.focus_on_mount)
+                                    {
+                                        window.request_mount_focus(focus_handle);
+                                    }
                                     self.paint_keyboard_listeners(window, cx);
                                     f(&style, window, cx);
 
@@ -1782,6 +1979,11 @@ impl Interactivity {
                                         );
 
                                         if let Some(group) = self.group.as_ref() {
+                                            if self.tracked_focus_handle.is_some()
+                                                || self.group_focus_handle.is_some()
+                                            {
+                                                GroupFocusHandles::pop(group, cx);
+                                            }
                                             GroupHitboxes::pop(group, cx);
                                         }
                                     }
@@ -1916,6 +2118,7 @@ impl Interactivity {
 
     fn paint_mouse_listeners(
         &mut self,
+        global_id: Option<&GlobalElementId>,
         hitbox: &Hitbox,
         element_state: Option<&mut InteractiveElementState>,
         window: &mut Window,
@@ -1932,6 +2135,7 @@ impl Interactivity {
                     && !window.default_prevented()
                 {
                     window.focus(&focus_handle);
+                    window.focus_visible = false;
                     // If there is a parent that is also focusable, prevent it
                     // from transferring focus because we already did so.
                     window.prevent_default();
@@ -1980,6 +2184,11 @@ impl Interactivity {
                     cx.notify(current_view);
                 }
             });
+            window.on_mouse_event(move |_: &MouseExitEvent, phase, _, cx| {
+                if phase == DispatchPhase::Capture && was_hovered {
+                    cx.notify(current_view);
+                }
+            });
         }
         let drag_cursor_style = self.base_style.as_ref().mouse_cursor;
 
@@ -2020,7 +2229,7 @@ impl Interactivity {
             });
         }
 
-        if let Some(element_state) = element_state {
+        if let Some((element_state, global_id)) = element_state.zip(global_id) {
             if !click_listeners.is_empty() || drag_listener.is_some() {
                 let pending_mouse_down = element_state
                     .pending_mouse_down
@@ -2035,12 +2244,14 @@ impl Interactivity {
                 window.on_mouse_event({
                     let pending_mouse_down = pending_mouse_down.clone();
                     let hitbox = hitbox.clone();
+                    let global_id = global_id.clone();
                     move |event: &MouseDownEvent, phase, window, _cx| {
                         if phase == DispatchPhase::Bubble
                             && event.button == MouseButton::Left
                             && hitbox.is_hovered(window)
                         {
                             *pending_mouse_down.borrow_mut() = Some(event.clone());
+                            window.capture_pointer(&global_id, hitbox.id, event.button);
                             window.refresh();
                         }
                     }
@@ -2056,10 +2267,8 @@ impl Interactivity {
 
                         let mut pending_mouse_down = pending_mouse_down.borrow_mut();
                         if let Some(mouse_down) = pending_mouse_down.clone() {
-                            if !cx.has_active_drag()
-                                && (event.position - mouse_down.position).magnitude()
-                                    > DRAG_THRESHOLD
-                            {
+                            let distance = (event.position - mouse_down.position).magnitude();
+                            if !cx.has_active_drag() && distance > DRAG_THRESHOLD {
                                 if let Some((drag_value, drag_listener)) = drag_listener.take() {
                                     *clicked_state.borrow_mut() = ElementClickedState::default();
                                     let cursor_offset = event.position - hitbox.origin;
@@ -2076,10 +2285,17 @@ impl Interactivity {
                                         cursor_style: drag_cursor_style,
                                     });
                                     pending_mouse_down.take();
+                                    // Drop targets find the drag by hovering, so stop capturing.
+                                    window.release_pointer();
                                     window.refresh();
                                     cx.stop_propagation();
                                 }
                             }
+                            // A press that wanders off is no longer a click, even if it did not
+                            // start a drag.
+                            if distance > CLICK_CANCEL_THRESHOLD {
+                                pending_mouse_down.take();
+                            }
                         }
                     }
                 });
@@ -2093,10 +2309,15 @@ impl Interactivity {
                         // propagation.
                         DispatchPhase::Capture => {
                             let mut pending_mouse_down = pending_mouse_down.borrow_mut();
-                            if pending_mouse_down.is_some() && hitbox.is_hovered(window) {
+                            // Releasing a different button doesn't complete the click.
+                            let same_button = pending_mouse_down
+                                .as_ref()
+                                .is_some_and(|mouse_down| mouse_down.button == event.button);
+                            // The press captured the pointer, so check where it really is.
+                            if same_button && hitbox.is_hovered_ignoring_capture(window) {
                                 captured_mouse_down = pending_mouse_down.take();
                                 window.refresh();
-                            } else if pending_mouse_down.is_some() {
+                            } else if same_button {
                                 // Clear the pending mouse down event (without firing click handlers)
                                 // if the hitbox is not being hovered.
                                 // This avoids dragging elements that changed their position
@@ -2122,6 +2343,52 @@ impl Interactivity {
                 });
             }
 
+            if let Some((delay, _)) = self.delayed_hover_style.as_ref() {
+                let delay = *delay;
+                let delayed_hover = element_state
+                    .delayed_hover
+                    .get_or_insert_with(Default::default)
+                    .clone();
+                let current_view = window.current_view();
+
+                window.on_mouse_event({
+                    let delayed_hover = delayed_hover.clone();
+                    let hitbox = hitbox.clone();
+                    move |_: &MouseMoveEvent, phase, window, cx| {
+                        if phase != DispatchPhase::Bubble {
+                            return;
+                        }
+                        let mut state = delayed_hover.borrow_mut();
+                        if !hitbox.is_hovered(window) {
+                            if matches!(mem::take(&mut *state), DelayedHoverState::Engaged) {
+                                cx.notify(current_view);
+                            }
+                        } else if matches!(*state, DelayedHoverState::Idle) {
+                            let task = window.spawn(cx, {
+                                let delayed_hover = delayed_hover.clone();
+                                async move |cx| {
+                                    cx.background_executor().timer(delay).await;
+                                    *delayed_hover.borrow_mut() = DelayedHoverState::Engaged;
+                                    cx.update(|_, cx| cx.notify(current_view)).ok();
+                                }
+                            });
+                            *state = DelayedHoverState::Waiting { _task: task };
+                        }
+                    }
+                });
+                window.on_mouse_event(move |_: &MouseExitEvent, phase, _, cx| {
+                    if phase != DispatchPhase::Bubble {
+                        return;
+                    }
+                    if matches!(
+                        mem::take(&mut *delayed_hover.borrow_mut()),
+                        DelayedHoverState::Engaged
+                    ) {
+                        cx.notify(current_view);
+                    }
+                });
+            }
+
             if let Some(hover_listener) = self.hover_listener.take() {
                 let hitbox = hitbox.clone();
                 let was_hovered = element_state
@@ -2133,20 +2400,30 @@ impl Interactivity {
                     .get_or_insert_with(Default::default)
                     .clone();
 
-                window.on_mouse_event(move |_: &MouseMoveEvent, phase, window, cx| {
-                    if phase != DispatchPhase::Bubble {
-                        return;
-                    }
-                    let is_hovered = has_mouse_down.borrow().is_none()
-                        && !cx.has_active_drag()
-                        && hitbox.is_hovered(window);
-                    let mut was_hovered = was_hovered.borrow_mut();
+                let hover_listener = Rc::new(hover_listener);
+                window.on_mouse_event({
+                    let was_hovered = was_hovered.clone();
+                    let hover_listener = hover_listener.clone();
+                    move |_: &MouseMoveEvent, phase, window, cx| {
+                        if phase != DispatchPhase::Bubble {
+                            return;
+                        }
+                        let is_hovered = has_mouse_down.borrow().is_none()
+                            && !cx.has_active_drag()
+                            && hitbox.is_hovered(window);
+                        let mut was_hovered = was_hovered.borrow_mut();
 
-                    if is_hovered != *was_hovered {
-                        *was_hovered = is_hovered;
-                        drop(was_hovered);
+                        if is_hovered != *was_hovered {
+                            *was_hovered = is_hovered;
+                            drop(was_hovered);
 
-                        hover_listener(&is_hovered, window, cx);
+                            hover_listener(&is_hovered, window, cx);
+                        }
+                    }
+                });
+                window.on_mouse_event(move |_: &MouseExitEvent, phase, window, cx| {
+                    if phase == DispatchPhase::Bubble && mem::take(&mut *was_hovered.borrow_mut()) {
+                        hover_listener(&false, window, cx);
                     }
                 });
             }
@@ -2194,29 +2471,93 @@ impl Interactivity {
                 .clicked_state
                 .get_or_insert_with(Default::default)
                 .clone();
+            let clicked_groups = element_state
+                .clicked_groups
+                .get_or_insert_with(Default::default)
+                .clone();
             if active_state.borrow().is_clicked() {
-                window.on_mouse_event(move |_: &MouseUpEvent, phase, window, _cx| {
+                let pressed_hitboxes = {
+                    let active_state = active_state.borrow();
+                    let mut pressed_hitboxes = SmallVec::<[HitboxId; 2]>::new();
+                    if active_state.element {
+                        pressed_hitboxes.push(hitbox.id);
+                    }
+                    if active_state.group {
+                        pressed_hitboxes.extend(
+                            clicked_groups
+                                .borrow()
+                                .iter()
+                                .filter_map(|group| GroupHitboxes::get(group, cx)),
+                        );
+                    }
+                    pressed_hitboxes
+                };
+                let was_hovered = pressed_hitboxes
+                    .iter()
+                    .map(|hitbox_id| hitbox_id.is_hovered_ignoring_capture(window))
+                    .collect::<SmallVec<[bool; 2]>>();
+                // The active style only shows while the pointer is over the pressed element, so
+                // redraw when the pointer leaves or returns before the button is released.
+                window.on_mouse_event(move |_: &MouseMoveEvent, phase, window, _cx| {
+                    if phase == DispatchPhase::Capture
+                        && pressed_hitboxes
+                            .iter()
+                            .zip(&was_hovered)
+                            .any(|(hitbox_id, was_hovered)| {
+                                hitbox_id.is_hovered_ignoring_capture(window) != *was_hovered
+                            })
+                    {
+                        window.refresh();
+                    }
+                });
+                window.on_mouse_event({
+                    let active_state = active_state.clone();
+                    move |_: &MouseUpEvent, phase, window, _cx| {
+                        if phase == DispatchPhase::Capture {
+                            *active_state.borrow_mut() = ElementClickedState::default();
+                            window.refresh();
+                        }
+                    }
+                });
+                // The release may happen outside the window, where it is never reported.
+                window.on_mouse_event(move |_: &MouseExitEvent, phase, window, _cx| {
                     if phase == DispatchPhase::Capture {
                         *active_state.borrow_mut() = ElementClickedState::default();
                         window.refresh();
                     }
                 });
             } else {
-                let active_group_hitbox = self
-                    .group_active_style
-                    .as_ref()
-                    .and_then(|group_active| GroupHitboxes::get(&group_active.group, cx));
+                let active_group_hitboxes = self
+                    .group_active_styles
+                    .iter()
+                    .filter_map(|group_active| {
+                        let group_hitbox_id = GroupHitboxes::get(&group_active.group, cx)?;
+                        Some((group_active.group.clone(), group_hitbox_id))
+                    })
+                    .collect::<SmallVec<[_; 1]>>();
                 let hitbox = hitbox.clone();
-                window.on_mouse_event(move |_: &MouseDownEvent, phase, window, _cx| {
+                let global_id = global_id.clone();
+                let has_active_style = self.active_style.is_some();
+                window.on_mouse_event(move |event: &MouseDownEvent, phase, window, _cx| {
                     if phase == DispatchPhase::Bubble && !window.default_prevented() {
-                        let group_hovered = active_group_hitbox
-                            .map_or(false, |group_hitbox_id| group_hitbox_id.is_hovered(window));
+                        let groups = active_group_hitboxes
+                            .iter()
+                            .filter(|(_, group_hitbox_id)| group_hitbox_id.is_hovered(window))
+                            .map(|(group, _)| group.clone())
+                            .collect::<SmallVec<_>>();
                         let element_hovered = hitbox.is_hovered(window);
-                        if group_hovered || element_hovered {
+                        if !groups.is_empty() || element_hovered {
                             *active_state.borrow_mut() = ElementClickedState {
-                                group: group_hovered,
+                                group: !groups.is_empty(),
                                 element: element_hovered,
                             };
+                            *clicked_groups.borrow_mut() = groups;
+                            if element_hovered
+                                && has_active_style
+                                && event.button == MouseButton::Left
+                            {
+                                window.capture_pointer(&global_id, hitbox.id, event.button);
+                            }
                             window.refresh();
                         }
                     }
@@ -2258,12 +2599,12 @@ impl Interactivity {
     }
 
     fn paint_hover_group_handler(&self, window: &mut Window, cx: &mut App) {
-        let group_hitbox = self
-            .group_hover_style
-            .as_ref()
-            .and_then(|group_hover| GroupHitboxes::get(&group_hover.group, cx));
+        let group_hitboxes = self
+            .group_hover_styles
+            .iter()
+            .filter_map(|group_hover| GroupHitboxes::get(&group_hover.group, cx));
 
-        if let Some(group_hitbox) = group_hitbox {
+        for group_hitbox in group_hitboxes {
             let was_hovered = group_hitbox.is_hovered(window);
             let current_view = window.current_view();
             window.on_mouse_event(move |_: &MouseMoveEvent, phase, window, cx| {
@@ -2272,6 +2613,11 @@ impl Interactivity {
                     cx.notify(current_view);
                 }
             });
+            window.on_mouse_event(move |_: &MouseExitEvent, phase, _, cx| {
+                if phase == DispatchPhase::Capture && was_hovered {
+                    cx.notify(current_view);
+                }
+            });
         }
     }
 
@@ -2287,13 +2633,22 @@ impl Interactivity {
             let allow_concurrent_scroll = style.allow_concurrent_scroll;
             let restrict_scroll_to_axis = style.restrict_scroll_to_axis;
             let line_height = window.line_height();
+            let (_, scroll_max) = self.scroll_extent(hitbox.bounds, style, window);
+            let tracked_scroll_handle = self.tracked_scroll_handle.clone();
             let hitbox = hitbox.clone();
             let current_view = window.current_view();
             window.on_mouse_event(move |event: &ScrollWheelEvent, phase, window, cx| {
                 if phase == DispatchPhase::Bubble && hitbox.should_handle_scroll(window) {
                     let mut scroll_offset = scroll_offset.borrow_mut();
                     let old_scroll_offset = *scroll_offset;
-                    let delta = event.delta.pixel_delta(line_height);
+                    let mut delta = event.delta.pixel_delta(line_height);
+                    // A nested scroll container has already moved along these axes.
+                    if window.consumed_scroll_axes.x {
+                        delta.x = Pixels::ZERO;
+                    }
+                    if window.consumed_scroll_axes.y {
+                        delta.y = Pixels::ZERO;
+                    }
 
                     let mut delta_x = Pixels::ZERO;
                     if overflow.x == Overflow::Scroll {
@@ -2318,9 +2673,40 @@ impl Interactivity {
                             delta_x = Pixels::ZERO;
                         }
                     }
-                    scroll_offset.y += delta_y;
-                    scroll_offset.x += delta_x;
-                    if *scroll_offset != old_scroll_offset {
+                    if delta_x.is_zero() && delta_y.is_zero() {
+                        return;
+                    }
+                    let mut retargeted = None;
+                    if let Some(handle) = tracked_scroll_handle.as_ref() {
+                        if let Some(animation) = handle.0.borrow_mut().animation.as_mut() {
+                            let target = point(
+                                (animation.to.x + delta_x).clamp(-scroll_max.width, px(0.)),
+                                (animation.to.y + delta_y).clamp(-scroll_max.height, px(0.)),
+                            );
+                            retargeted =
+                                Some(point(target.x != animation.to.x, target.y != animation.to.y));
+                            animation.retarget(*scroll_offset, target);
+                        }
+                    }
+                    let moved = match retargeted {
+                        Some(moved) => moved,
+                        None => {
+                            scroll_offset.x =
+                                (scroll_offset.x + delta_x).clamp(-scroll_max.width, px(0.));
+                            scroll_offset.y =
+                                (scroll_offset.y + delta_y).clamp(-scroll_max.height, px(0.));
+                            point(
+                                scroll_offset.x != old_scroll_offset.x,
+                                scroll_offset.y != old_scroll_offset.y,
+                            )
+                        }
+                    };
+                    // Only claim an axis when this container moved along it with that axis's own
+                    // delta. A container resting at its edge, or one that remapped the delta onto
+                    // its other axis, leaves the axis to the scroll containers around it.
+                    window.consumed_scroll_axes.x |= moved.x && !delta.x.is_zero();
+                    window.consumed_scroll_axes.y |= moved.y && !delta.y.is_zero();
+                    if moved.x || moved.y {
                         cx.notify(current_view);
                     }
                 }
@@ -2328,6 +2714,61 @@ impl Interactivity {
         }
     }
 
+    fn paint_keyboard_scroll_listener(&self, hitbox: &Hitbox, style: &Style, window: &mut Window) {
+        if !self.keyboard_scroll {
+            return;
+        }
+        let (Some(scroll_offset), Some(focus_handle)) =
+            (self.scroll_offset.clone(), self.tracked_focus_handle.clone())
+        else {
+            return;
+        };
+        let vertical = style.overflow.y == Overflow::Scroll;
+        let horizontal = style.overflow.x == Overflow::Scroll;
+        if !vertical && !horizontal {
+            return;
+        }
+
+        let (_, scroll_max) = self.scroll_extent(hitbox.bounds, style, window);
+        let viewport_size = hitbox.bounds.size;
+        let line_height = window.line_height();
+        let tracked_scroll_handle = self.tracked_scroll_handle.clone();
+        let current_view = window.current_view();
+        // Key listeners only run once no key binding has handled the keystroke, so actions bound
+        // to these keys take precedence over scrolling.
+        window.on_key_event(move |event: &KeyDownEvent, phase, window, cx| {
+            if phase != DispatchPhase::Bubble
+                || event.keystroke.modifiers.modified()
+                || !focus_handle.is_focused(window)
+            {
+                return;
+            }
+
+            let mut scroll_offset = scroll_offset.borrow_mut();
+            let old_scroll_offset = *scroll_offset;
+            match event.keystroke.key.as_str() {
+                "up" if vertical => scroll_offset.y += line_height,
+                "down" if vertical => scroll_offset.y -= line_height,
+                "pageup" if vertical => scroll_offset.y += viewport_size.height,
+                "pagedown" if vertical => scroll_offset.y -= viewport_size.height,
+                "home" if vertical => scroll_offset.y = px(0.),
+                "end" if vertical => scroll_offset.y = -scroll_max.height,
+                "left" if horizontal => scroll_offset.x += line_height,
+                "right" if horizontal => scroll_offset.x -= line_height,
+                _ => return,
+            }
+            scroll_offset.x = scroll_offset.x.clamp(-scroll_max.width, px(0.));
+            scroll_offset.y = scroll_offset.y.clamp(-scroll_max.height, px(0.));
+            if *scroll_offset != old_scroll_offset {
+                if let Some(handle) = tracked_scroll_handle.as_ref() {
+                    handle.stop_animation();
+                }
+                cx.stop_propagation();
+                cx.notify(current_view);
+            }
+        });
+    }
+
     /// Compute the visual style for this element, based on the current bounds and the element's state.
     pub fn compute_style(
         &self,
@@ -2367,11 +2808,25 @@ impl Interactivity {
                     style.refine(focus_style);
                 }
             }
+
+            if let Some(focus_visible_style) = self.focus_visible_style.as_ref() {
+                if window.focus_visible && focus_handle.is_focused(window) {
+                    style.refine(focus_visible_style);
+                }
+            }
+        }
+
+        for group_focus in &self.group_focus_styles {
+            if let Some(group_focus_handle) = GroupFocusHandles::get(&group_focus.group, cx) {
+                if group_focus_handle.contains_focused(window, cx) {
+                    style.refine(&group_focus.style);
+                }
+            }
         }
 
         if let Some(hitbox) = hitbox {
             if !cx.has_active_drag() {
-                if let Some(group_hover) = self.group_hover_style.as_ref() {
+                for group_hover in &self.group_hover_styles {
                     if let Some(group_hitbox_id) = GroupHitboxes::get(&group_hover.group, cx) {
                         if group_hitbox_id.is_hovered(window) {
                             style.refine(&group_hover.style);
@@ -2384,6 +2839,18 @@ impl Interactivity {
                         style.refine(hover_style);
                     }
                 }
+
+                if let Some((_, delayed_hover_style)) = self.delayed_hover_style.as_ref() {
+                    let engaged = element_state
+                        .as_ref()
+                        .and_then(|element_state| element_state.delayed_hover.as_ref())
+                        .is_some_and(|delayed_hover| {
+                            matches!(*delayed_hover.borrow(), DelayedHoverState::Engaged)
+                        });
+                    if engaged && hitbox.is_hovered(window) {
+                        style.refine(delayed_hover_style);
+                    }
+                }
             }
 
             if let Some(drag) = cx.active_drag.take() {
@@ -2419,18 +2886,30 @@ impl Interactivity {
         }
 
         if let Some(element_state) = element_state {
-            let clicked_state = element_state
+            let clicked_state = *element_state
                 .clicked_state
                 .get_or_insert_with(Default::default)
                 .borrow();
-            if clicked_state.group {
-                if let Some(group) = self.group_active_style.as_ref() {
-                    style.refine(&group.style)
+            let clicked_groups = element_state
+                .clicked_groups
+                .get_or_insert_with(Default::default)
+                .borrow();
+            for group_active in &self.group_active_styles {
+                if clicked_state.group
+                    && clicked_groups.contains(&group_active.group)
+                    && GroupHitboxes::get(&group_active.group, cx).is_some_and(|group_hitbox_id| {
+                        group_hitbox_id.is_hovered_ignoring_capture(window)
+                    })
+                {
+                    style.refine(&group_active.style)
                 }
             }
 
+            // A press captures the pointer, so check where the pointer really is.
             if let Some(active_style) = self.active_style.as_ref() {
-                if clicked_state.element {
+                if clicked_state.element
+                    && hitbox.is_some_and(|hitbox| hitbox.is_hovered_ignoring_capture(window))
+                {
                     style.refine(active_style)
                 }
             }
@@ -2446,7 +2925,10 @@ impl Interactivity {
 pub struct InteractiveElementState {
     pub(crate) focus_handle: Option<FocusHandle>,
     pub(crate) clicked_state: Option<Rc<RefCell<ElementClickedState>>>,
+    /// The groups that were pressed the last time `clicked_state.group` was set.
+    pub(crate) clicked_groups: Option<Rc<RefCell<SmallVec<[SharedString; 1]>>>>,
     pub(crate) hover_state: Option<Rc<RefCell<bool>>>,
+    pub(crate) delayed_hover: Option<Rc<RefCell<DelayedHoverState>>>,
     pub(crate) pending_mouse_down: Option<Rc<RefCell<Option<MouseDownEvent>>>>,
     pub(crate) scroll_offset: Option<Rc<RefCell<Point<Pixels>>>>,
     pub(crate) active_tooltip: Option<Rc<RefCell<Option<ActiveTooltip>>>>,
@@ -2468,6 +2950,18 @@ impl ElementClickedState {
     }
 }
 
+/// Progress of an element's [`StatefulInteractiveElement::hover_delayed`] style.
+#[derive(Default)]
+pub(crate) enum DelayedHoverState {
+    /// The mouse is not over the element.
+    #[default]
+    Idle,
+    /// The mouse is over the element, and the style engages once this task's delay elapses.
+    Waiting { _task: Task<()> },
+    /// The mouse has stayed over the element for the whole delay.
+    Engaged,
+}
+
 pub(crate) enum ActiveTooltip {
     /// Currently delaying before showing the tooltip.
     WaitingForShow { _task: Task<()> },
@@ -2773,7 +3267,46 @@ impl GroupHitboxes {
     }
 
     pub fn pop(name: &SharedString, cx: &mut App) {
-        cx.default_global::<Self>().0.get_mut(name).unwrap().pop();
+        let groups = &mut cx.default_global::<Self>().0;
+        if let Some(hitbox_ids) = groups.get_mut(name) {
+            hitbox_ids.pop();
+            if hitbox_ids.is_empty() {
+                groups.remove(name);
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct GroupFocusHandles(HashMap<SharedString, SmallVec<[FocusHandle; 1]>>);
+
+impl Global for GroupFocusHandles {}
+
+impl GroupFocusHandles {
+    pub fn get(name: &SharedString, cx: &mut App) -> Option<FocusHandle> {
+        cx.default_global::<Self>()
+            .0
+            .get(name)
+            .and_then(|focus_handles| focus_handles.last())
+            .cloned()
+    }
+
+    pub fn push(name: SharedString, focus_handle: FocusHandle, cx: &mut App) {
+        cx.default_global::<Self>()
+            .0
+            .entry(name)
+            .or_default()
+            .push(focus_handle);
+    }
+
+    pub fn pop(name: &SharedString, cx: &mut App) {
+        let groups = &mut cx.default_global::<Self>().0;
+        if let Some(focus_handles) = groups.get_mut(name) {
+            focus_handles.pop();
+            if focus_handles.is_empty() {
+                groups.remove(name);
+            }
+        }
     }
 }
 
@@ -3009,6 +3542,38 @@ where
     }
 }
 
+/// How far to scroll a container this frame while something is dragged near its edges, faster
+/// the closer the pointer gets to the edge.
+fn drag_autoscroll_step(
+    bounds: Bounds<Pixels>,
+    position: Point<Pixels>,
+    overflow: Point<Overflow>,
+) -> Point<Pixels> {
+    fn axis_step(position: Pixels, start: Pixels, end: Pixels) -> Pixels {
+        let from_start = position - start;
+        let from_end = end - position;
+        if from_start < DRAG_AUTOSCROLL_EDGE {
+            DRAG_AUTOSCROLL_MAX_STEP * (1. - from_start / DRAG_AUTOSCROLL_EDGE)
+        } else if from_end < DRAG_AUTOSCROLL_EDGE {
+            -DRAG_AUTOSCROLL_MAX_STEP * (1. - from_end / DRAG_AUTOSCROLL_EDGE)
+        } else {
+            Pixels::ZERO
+        }
+    }
+
+    if !bounds.contains(&position) {
+        return Point::default();
+    }
+    let mut step = Point::default();
+    if overflow.x == Overflow::Scroll {
+        step.x = axis_step(position.x, bounds.left(), bounds.right());
+    }
+    if overflow.y == Overflow::Scroll {
+        step.y = axis_step(position.y, bounds.top(), bounds.bottom());
+    }
+    step
+}
+
 /// Represents an element that can be scrolled *to* in its parent element.
 ///
 /// Contrary to [ScrollHandle::scroll_to_item], an anchored element does not have to be an immediate child of the parent.
@@ -3045,7 +3610,48 @@ struct ScrollHandleState {
     padded_content_size: Size<Pixels>,
     child_bounds: Vec<Bounds<Pixels>>,
     scroll_to_bottom: bool,
+    follow_tail: bool,
+    scroll_max: Size<Pixels>,
     overflow: Point<Overflow>,
+    animation: Option<ScrollAnimation>,
+}
+
+struct ScrollAnimation {
+    from: Point<Pixels>,
+    to: Point<Pixels>,
+    start: Instant,
+    duration: Duration,
+    easing: Rc<dyn Fn(f32) -> f32>,
+}
+
+impl ScrollAnimation {
+    fn offset_at(&self, now: Instant) -> (Point<Pixels>, bool) {
+        let progress = if self.duration.is_zero() {
+            1.
+        } else {
+            (now.saturating_duration_since(self.start).as_secs_f32() / self.duration.as_secs_f32())
+                .min(1.)
+        };
+        let delta = (self.easing)(progress);
+        (self.from + (self.to - self.from) * delta, progress >= 1.)
+    }
+
+    fn retarget(&mut self, from: Point<Pixels>, to: Point<Pixels>) {
+        self.from = from;
+        self.to = to;
+        self.start = Instant::now();
+    }
+}
+
+impl Debug for ScrollAnimation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScrollAnimation")
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("start", &self.start)
+            .field("duration", &self.duration)
+            .finish_non_exhaustive()
+    }
 }
 
 /// A handle to the scrollable aspects of an element.
@@ -3110,14 +3716,21 @@ impl ScrollHandle {
         self.0.borrow().padded_content_size
     }
 
+    /// Get the maximum distance the content can be scrolled along each axis, as of the last
+    /// frame. The offset is clamped to `-max_offset()..=0` whenever the element is laid out.
+    pub fn max_offset(&self) -> Size<Pixels> {
+        self.0.borrow().scroll_max
+    }
+
     /// scroll_to_item scrolls the minimal amount to ensure that the child is
     /// fully visible
     pub fn scroll_to_item(&self, ix: usize) {
-        let state = self.0.borrow();
+        let mut state = self.0.borrow_mut();
 
-        let Some(bounds) = state.child_bounds.get(ix) else {
+        let Some(bounds) = state.child_bounds.get(ix).cloned() else {
             return;
         };
+        state.animation = None;
 
         let mut scroll_offset = state.offset.borrow_mut();
 
@@ -3141,17 +3754,62 @@ impl ScrollHandle {
     /// Scrolls to the bottom.
     pub fn scroll_to_bottom(&self) {
         let mut state = self.0.borrow_mut();
+        state.animation = None;
         state.scroll_to_bottom = true;
     }
 
+    /// Scrolls to the top.
+    pub fn scroll_to_top(&self) {
+        let mut state = self.0.borrow_mut();
+        state.animation = None;
+        state.offset.borrow_mut().y = px(0.);
+    }
+
+    /// Keeps the element scrolled to the bottom as its content grows, as long as it was
+    /// scrolled to the bottom before. Scrolling up pauses following until the bottom is reached again.
+    pub fn set_follow_tail(&self, follow_tail: bool) {
+        self.0.borrow_mut().follow_tail = follow_tail;
+    }
+
     /// Set the offset explicitly. The offset is the distance from the top left of the
     /// parent container to the top left of the first child.
     /// As you scroll further down the offset becomes more negative.
     pub fn set_offset(&self, mut position: Point<Pixels>) {
-        let state = self.0.borrow();
+        let mut state = self.0.borrow_mut();
+        state.animation = None;
         *state.offset.borrow_mut() = position;
     }
 
+    /// Scrolls smoothly to the given offset over `duration`, shaping the motion with an easing
+    /// function such as [`linear`](crate::linear) or the one returned by
+    /// [`ease_out_quint()`](crate::ease_out_quint).
+    ///
+    /// The animation advances each time the tracking element is painted and keeps requesting
+    /// frames until it finishes, but starting it does not redraw anything. Notify the view that
+    /// renders the element after calling this. Wheel input during the animation moves its target
+    /// instead of interrupting it, while any other scrolling cancels it.
+    pub fn animate_to(
+        &self,
+        offset: Point<Pixels>,
+        duration: Duration,
+        easing: impl Fn(f32) -> f32 + 'static,
+    ) {
+        let mut state = self.0.borrow_mut();
+        let from = *state.offset.borrow();
+        state.animation = Some(ScrollAnimation {
+            from,
+            to: offset,
+            start: Instant::now(),
+            duration,
+            easing: Rc::new(easing),
+        });
+    }
+
+    /// Stops an in-flight [`ScrollHandle::animate_to`], leaving the offset where it is.
+    pub fn stop_animation(&self) {
+        self.0.borrow_mut().animation = None;
+    }
+
     /// Get the logical scroll top, based on a child index and a pixel offset.
     pub fn logical_scroll_top(&self) -> (usize, Pixels) {
         let ix = self.top_item();
@@ -3172,3 +3830,1006 @@ impl ScrollHandle {
         self.0.borrow().child_bounds.len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as gpui, AnyElement, AppContext as _, Context, EmptyView, FocusHandle,
+        FocusableElement, InteractiveElement, IntoElement, Modifiers, MouseButton, MouseExitEvent,
+        ParentElement, Render, ScrollDelta, ScrollHandle, ScrollWheelEvent,
+        StatefulInteractiveElement, Styled, TestAppContext, Window, canvas, div, point,
+        prelude::FluentBuilder as _, px, size,
+    };
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+        time::Duration,
+    };
+
+    #[gpui::test]
+    fn test_scroll_handle_follow_tail(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let scroll_handle = ScrollHandle::new();
+        scroll_handle.set_follow_tail(true);
+
+        let mut draw_lines = |line_count: usize| {
+            cx.draw(point(px(0.), px(0.)), size(px(100.), px(20.)), |_, _| {
+                div()
+                    .id("log")
+                    .w(px(100.))
+                    .h(px(20.))
+                    .flex()
+                    .flex_col()
+                    .overflow_y_scroll()
+                    .track_scroll(&scroll_handle)
+                    .children((0..line_count).map(|_| div().flex_none().h(px(10.))))
+            });
+        };
+
+        draw_lines(3);
+        assert_eq!(scroll_handle.offset().y, px(-10.));
+        draw_lines(5);
+        assert_eq!(scroll_handle.offset().y, px(-30.));
+
+        scroll_handle.set_offset(point(px(0.), px(-10.)));
+        draw_lines(6);
+        assert_eq!(scroll_handle.offset().y, px(-10.));
+    }
+
+    #[gpui::test]
+    fn test_scroll_handle_clamps_to_content(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let scroll_handle = ScrollHandle::new();
+
+        let mut draw_lines = |line_count: usize| {
+            cx.draw(point(px(0.), px(0.)), size(px(100.), px(20.)), |_, _| {
+                div()
+                    .id("list")
+                    .w(px(100.))
+                    .h(px(20.))
+                    .flex()
+                    .flex_col()
+                    .overflow_y_scroll()
+                    .track_scroll(&scroll_handle)
+                    .children((0..line_count).map(|_| div().flex_none().h(px(10.))))
+            });
+        };
+
+        draw_lines(5);
+        assert_eq!(scroll_handle.max_offset(), size(px(0.), px(30.)));
+        scroll_handle.set_offset(point(px(0.), px(-50.)));
+        draw_lines(5);
+        assert_eq!(scroll_handle.offset().y, px(-30.));
+
+        draw_lines(1);
+        assert_eq!(scroll_handle.max_offset(), size(px(0.), px(0.)));
+        assert_eq!(scroll_handle.offset().y, px(0.));
+    }
+
+    #[gpui::test]
+    fn test_scroll_handle_animate_to(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let scroll_handle = ScrollHandle::new();
+
+        let mut draw_lines = || {
+            cx.draw(point(px(0.), px(0.)), size(px(100.), px(20.)), |_, _| {
+                div()
+                    .id("list")
+                    .w(px(100.))
+                    .h(px(20.))
+                    .flex()
+                    .flex_col()
+                    .overflow_y_scroll()
+                    .track_scroll(&scroll_handle)
+                    .children((0..10).map(|_| div().flex_none().h(px(10.))))
+            });
+        };
+
+        scroll_handle.animate_to(point(px(0.), px(-50.)), Duration::ZERO, gpui::linear);
+        draw_lines();
+        assert_eq!(scroll_handle.offset().y, px(-50.));
+
+        scroll_handle.animate_to(point(px(0.), px(0.)), Duration::from_secs(1000), gpui::linear);
+        draw_lines();
+        let offset = scroll_handle.offset().y;
+        assert!(offset >= px(-50.) && offset < px(-49.));
+
+        scroll_handle.stop_animation();
+        draw_lines();
+        assert_eq!(scroll_handle.offset().y, offset);
+
+        scroll_handle.animate_to(point(px(0.), px(-80.)), Duration::from_secs(1000), gpui::linear);
+        draw_lines();
+        scroll_handle.scroll_to_top();
+        draw_lines();
+        assert_eq!(scroll_handle.offset().y, px(0.));
+
+        scroll_handle.animate_to(point(px(0.), px(0.)), Duration::from_secs(1000), gpui::linear);
+        scroll_handle.scroll_to_bottom();
+        draw_lines();
+        draw_lines();
+        assert_eq!(scroll_handle.offset().y, px(-80.));
+
+        scroll_handle.animate_to(point(px(0.), px(0.)), Duration::from_secs(1000), gpui::linear);
+        scroll_handle.scroll_to_item(5);
+        draw_lines();
+        assert_eq!(scroll_handle.offset().y, px(-50.));
+    }
+
+    struct FocusableScrollView {
+        focus_handle: FocusHandle,
+        scroll_handle: ScrollHandle,
+        keyboard_scroll: bool,
+    }
+
+    impl Render for FocusableScrollView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            div()
+                .id("document")
+                .track_focus(&self.focus_handle)
+                .w(px(100.))
+                .h(px(20.))
+                .flex()
+                .flex_col()
+                .overflow_y_scroll()
+                .track_scroll(&self.scroll_handle)
+                .when(self.keyboard_scroll, |document| document.keyboard_scroll())
+                .children((0..10).map(|_| div().flex_none().h(px(10.))))
+        }
+    }
+
+    #[gpui::test]
+    fn test_keyboard_scrolling(cx: &mut TestAppContext) {
+        let scroll_handle = ScrollHandle::new();
+        let (view, cx) = cx.add_window_view(|_, cx| FocusableScrollView {
+            focus_handle: cx.focus_handle(),
+            scroll_handle: scroll_handle.clone(),
+            keyboard_scroll: true,
+        });
+        cx.update(|window, cx| window.focus(&view.read(cx).focus_handle));
+
+        cx.simulate_keystrokes("pagedown");
+        assert_eq!(scroll_handle.offset().y, px(-20.));
+        cx.simulate_keystrokes("end");
+        assert_eq!(scroll_handle.offset().y, px(-80.));
+        cx.simulate_keystrokes("pagedown");
+        assert_eq!(scroll_handle.offset().y, px(-80.));
+        cx.simulate_keystrokes("home");
+        assert_eq!(scroll_handle.offset().y, px(0.));
+
+        scroll_handle.animate_to(point(px(0.), px(-80.)), Duration::from_secs(1000), gpui::linear);
+        cx.simulate_keystrokes("down");
+        view.update(cx, |_, cx| cx.notify());
+        cx.run_until_parked();
+        assert_eq!(scroll_handle.offset().y, px(-10.));
+    }
+
+    #[gpui::test]
+    fn test_keyboard_scrolling_is_opt_in(cx: &mut TestAppContext) {
+        let scroll_handle = ScrollHandle::new();
+        let (view, cx) = cx.add_window_view(|_, cx| FocusableScrollView {
+            focus_handle: cx.focus_handle(),
+            scroll_handle: scroll_handle.clone(),
+            keyboard_scroll: false,
+        });
+        cx.update(|window, cx| window.focus(&view.read(cx).focus_handle));
+
+        cx.simulate_keystrokes("pagedown end down");
+        assert_eq!(scroll_handle.offset().y, px(0.));
+    }
+
+    #[gpui::test]
+    fn test_focus_visible_only_for_non_pointer_focus(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_, cx| FocusableScrollView {
+            focus_handle: cx.focus_handle(),
+            scroll_handle: ScrollHandle::new(),
+            keyboard_scroll: false,
+        });
+        let focus_handle = cx.update(|_, cx| view.read(cx).focus_handle.clone());
+
+        cx.simulate_mouse_down(point(px(5.), px(5.)), MouseButton::Left, Modifiers::none());
+        cx.update(|window, _| {
+            assert!(focus_handle.is_focused(window));
+            assert!(!window.focus_visible);
+        });
+
+        cx.update(|window, _| {
+            window.blur();
+            window.focus(&focus_handle);
+            assert!(window.focus_visible);
+        });
+    }
+
+    struct ClippedClickView {
+        clicks: Rc<Cell<usize>>,
+    }
+
+    impl Render for ClippedClickView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let clicks = self.clicks.clone();
+            div().size(px(100.)).overflow_hidden().child(
+                div()
+                    .id("child")
+                    .absolute()
+                    .left(px(50.))
+                    .w(px(100.))
+                    .h(px(20.))
+                    .on_click(move |_, _, _| clicks.set(clicks.get() + 1)),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn test_clipped_child_ignores_clicks_outside_parent(cx: &mut TestAppContext) {
+        let clicks = Rc::new(Cell::new(0));
+        let (_, cx) = cx.add_window_view(|_, _| ClippedClickView {
+            clicks: clicks.clone(),
+        });
+
+        cx.simulate_click(point(px(125.), px(10.)), Modifiers::none());
+        assert_eq!(clicks.get(), 0);
+        cx.simulate_click(point(px(75.), px(10.)), Modifiers::none());
+        assert_eq!(clicks.get(), 1);
+    }
+
+    struct HiddenOverlayView {
+        show_list: bool,
+        scroll_handle: ScrollHandle,
+        clicks: Rc<Cell<usize>>,
+    }
+
+    impl Render for HiddenOverlayView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let clicks = self.clicks.clone();
+            div()
+                .size(px(100.))
+                .child(
+                    div()
+                        .id("list")
+                        .w(px(100.))
+                        .h(px(20.))
+                        .flex()
+                        .flex_col()
+                        .overflow_y_scroll()
+                        .track_scroll(&self.scroll_handle)
+                        .when(!self.show_list, |this| this.invisible())
+                        .children((0..10).map(|_| div().flex_none().h(px(10.)))),
+                )
+                .child(
+                    div()
+                        .id("button")
+                        .absolute()
+                        .top(px(50.))
+                        .size(px(20.))
+                        .on_click(move |_, _, _| clicks.set(clicks.get() + 1)),
+                )
+                .child(
+                    div()
+                        .absolute()
+                        .top(px(50.))
+                        .size(px(20.))
+                        .occlude()
+                        .invisible(),
+                )
+        }
+    }
+
+    #[gpui::test]
+    fn test_invisible_elements_keep_state_and_skip_hit_testing(cx: &mut TestAppContext) {
+        let scroll_handle = ScrollHandle::new();
+        let clicks = Rc::new(Cell::new(0));
+        let (view, cx) = cx.add_window_view(|_, _| HiddenOverlayView {
+            show_list: true,
+            scroll_handle: scroll_handle.clone(),
+            clicks: clicks.clone(),
+        });
+
+        scroll_handle.set_offset(point(px(0.), px(-40.)));
+        view.update(cx, |view, cx| {
+            view.show_list = false;
+            cx.notify();
+        });
+        cx.run_until_parked();
+        view.update(cx, |view, cx| {
+            view.show_list = true;
+            cx.notify();
+        });
+        cx.run_until_parked();
+        assert_eq!(scroll_handle.offset().y, px(-40.));
+
+        cx.simulate_click(point(px(10.), px(60.)), Modifiers::none());
+        assert_eq!(clicks.get(), 1);
+    }
+
+    struct ClickView {
+        clicks: Rc<RefCell<Vec<Modifiers>>>,
+    }
+
+    impl Render for ClickView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let clicks = self.clicks.clone();
+            div().child(
+                div()
+                    .id("button")
+                    .size(px(20.))
+                    .on_click(move |event, _, _| clicks.borrow_mut().push(event.up.modifiers)),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn test_click_requires_down_and_up_inside_with_same_button(cx: &mut TestAppContext) {
+        let clicks = Rc::new(RefCell::new(Vec::new()));
+        let (_, cx) = cx.add_window_view(|_, _| ClickView {
+            clicks: clicks.clone(),
+        });
+        let inside = point(px(10.), px(10.));
+        let outside = point(px(50.), px(50.));
+
+        cx.simulate_mouse_down(inside, MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_up(outside, MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_down(outside, MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_up(inside, MouseButton::Left, Modifiers::none());
+        assert!(clicks.borrow().is_empty());
+
+        cx.simulate_mouse_down(inside, MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_up(inside, MouseButton::Right, Modifiers::none());
+        assert!(clicks.borrow().is_empty());
+        cx.simulate_mouse_up(inside, MouseButton::Left, Modifiers::shift());
+        assert_eq!(*clicks.borrow(), vec![Modifiers::shift()]);
+    }
+
+    #[gpui::test]
+    fn test_click_cancelled_by_mouse_movement(cx: &mut TestAppContext) {
+        let clicks = Rc::new(RefCell::new(Vec::new()));
+        let (_, cx) = cx.add_window_view(|_, _| ClickView {
+            clicks: clicks.clone(),
+        });
+
+        cx.simulate_mouse_down(point(px(5.), px(10.)), MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_move(point(px(15.), px(10.)), MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_up(point(px(15.), px(10.)), MouseButton::Left, Modifiers::none());
+        assert!(clicks.borrow().is_empty());
+
+        cx.simulate_mouse_down(point(px(5.), px(10.)), MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_move(point(px(7.), px(10.)), MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_up(point(px(7.), px(10.)), MouseButton::Left, Modifiers::none());
+        assert_eq!(clicks.borrow().len(), 1);
+    }
+
+    /// A view that renders the element tree built by its closure.
+    struct ElementView(Box<dyn Fn() -> AnyElement>);
+
+    impl ElementView {
+        fn new<E: IntoElement>(render: impl Fn() -> E + 'static) -> Self {
+            Self(Box::new(move || render().into_any_element()))
+        }
+    }
+
+    impl Render for ElementView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            (self.0)()
+        }
+    }
+
+    /// Fills its parent and records the opacity it was painted with.
+    fn opacity_probe(opacity: &Rc<Cell<f32>>) -> impl IntoElement {
+        let opacity = opacity.clone();
+        canvas(
+            |_, _, _| {},
+            move |_, _, window, _| opacity.set(window.element_opacity()),
+        )
+        .size_full()
+    }
+
+    #[gpui::test]
+    fn test_group_focus_style(cx: &mut TestAppContext) {
+        let opacity = Rc::new(Cell::new(0.));
+        let focus_handle = cx.update(|cx| cx.focus_handle());
+        let (_, cx) = cx.add_window_view(|_, _| {
+            let opacity = opacity.clone();
+            let focus_handle = focus_handle.clone();
+            ElementView::new(move || {
+                div()
+                    .group("pane")
+                    .track_focus(&focus_handle)
+                    .size(px(20.))
+                    .child(
+                        div()
+                            .size(px(10.))
+                            .group_focus("pane", |style| style.opacity(0.5))
+                            .child(opacity_probe(&opacity)),
+                    )
+            })
+        });
+        assert_eq!(opacity.get(), 1.);
+
+        cx.update(|window, _| window.focus(&focus_handle));
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 0.5);
+    }
+
+    #[gpui::test]
+    fn test_group_focus_style_with_focused_descendant(cx: &mut TestAppContext) {
+        let opacity = Rc::new(Cell::new(0.));
+        let focus_handle = cx.update(|cx| cx.focus_handle());
+        let (_, cx) = cx.add_window_view(|_, _| {
+            let opacity = opacity.clone();
+            let focus_handle = focus_handle.clone();
+            ElementView::new(move || {
+                div()
+                    .id("pane")
+                    .group("pane")
+                    .size(px(20.))
+                    .child(
+                        div()
+                            .size(px(10.))
+                            .group_focus("pane", |style| style.opacity(0.5))
+                            .child(opacity_probe(&opacity)),
+                    )
+                    .child(div().child(div().size(px(5.)).track_focus(&focus_handle)))
+            })
+        });
+        assert_eq!(opacity.get(), 1.);
+
+        cx.update(|window, _| window.focus(&focus_handle));
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 0.5);
+
+        cx.update(|window, _| window.blur());
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 1.);
+    }
+
+    fn delayed_hover_view(opacity: &Rc<Cell<f32>>) -> ElementView {
+        let opacity = opacity.clone();
+        ElementView::new(move || {
+            div().size(px(100.)).child(
+                div()
+                    .id("item")
+                    .size(px(20.))
+                    .hover_delayed(Duration::from_millis(100), |style| style.opacity(0.5))
+                    .child(opacity_probe(&opacity)),
+            )
+        })
+    }
+
+    #[gpui::test]
+    fn test_hover_delayed(cx: &mut TestAppContext) {
+        let opacity = Rc::new(Cell::new(0.));
+        let (_, cx) = cx.add_window_view(|_, _| delayed_hover_view(&opacity));
+        let inside = point(px(10.), px(10.));
+        let outside = point(px(50.), px(50.));
+
+        cx.simulate_mouse_move(inside, None, Modifiers::none());
+        cx.executor().advance_clock(Duration::from_millis(60));
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 1.);
+
+        // Leaving and entering again restarts the delay.
+        cx.simulate_mouse_move(outside, None, Modifiers::none());
+        cx.simulate_mouse_move(inside, None, Modifiers::none());
+        cx.executor().advance_clock(Duration::from_millis(60));
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 1.);
+
+        cx.executor().advance_clock(Duration::from_millis(60));
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 0.5);
+
+        cx.simulate_mouse_move(outside, None, Modifiers::none());
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 1.);
+    }
+
+    #[gpui::test]
+    fn test_window_deactivation_clears_hover_and_active_styles(cx: &mut TestAppContext) {
+        let opacity = Rc::new(Cell::new(0.));
+        let (_, cx) = cx.add_window_view(|_, _| {
+            let opacity = opacity.clone();
+            ElementView::new(move || {
+                div().size(px(100.)).child(
+                    div()
+                        .id("item")
+                        .size(px(20.))
+                        .hover(|style| style.opacity(0.5))
+                        .active(|style| style.opacity(0.25))
+                        .child(opacity_probe(&opacity)),
+                )
+            })
+        });
+        let inside = point(px(10.), px(10.));
+        cx.update(|window, _| window.activate_window());
+        cx.run_until_parked();
+
+        cx.simulate_mouse_move(inside, None, Modifiers::none());
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 0.5);
+        cx.deactivate_window();
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 1.);
+
+        cx.update(|window, _| window.activate_window());
+        cx.simulate_mouse_move(inside, None, Modifiers::none());
+        cx.simulate_mouse_down(inside, MouseButton::Left, Modifiers::none());
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 0.25);
+        cx.deactivate_window();
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 1.);
+    }
+
+    fn two_group_hover_view(opacity: &Rc<Cell<f32>>) -> ElementView {
+        let opacity = opacity.clone();
+        ElementView::new(move || {
+            div().group("pane").size(px(100.)).child(
+                div().group("row").w(px(100.)).h(px(50.)).child(
+                    div()
+                        .size(px(10.))
+                        .group_hover("pane", |style| style.opacity(0.5))
+                        .group_hover("row", |style| style.opacity(0.25))
+                        .child(opacity_probe(&opacity)),
+                ),
+            )
+        })
+    }
+
+    #[gpui::test]
+    fn test_group_hover_from_two_groups(cx: &mut TestAppContext) {
+        let opacity = Rc::new(Cell::new(0.));
+        let (_, cx) = cx.add_window_view(|_, _| two_group_hover_view(&opacity));
+        assert_eq!(opacity.get(), 1.);
+
+        cx.simulate_mouse_move(point(px(50.), px(75.)), None, Modifiers::none());
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 0.5);
+
+        cx.simulate_mouse_move(point(px(50.), px(25.)), None, Modifiers::none());
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 0.25);
+
+        cx.simulate_mouse_move(point(px(150.), px(150.)), None, Modifiers::none());
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 1.);
+    }
+
+    #[gpui::test]
+    fn test_group_hover_cleared_when_mouse_leaves_window(cx: &mut TestAppContext) {
+        let opacity = Rc::new(Cell::new(0.));
+        let (_, cx) = cx.add_window_view(|_, _| two_group_hover_view(&opacity));
+
+        cx.simulate_mouse_move(point(px(50.), px(25.)), None, Modifiers::none());
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 0.25);
+
+        cx.simulate_event(MouseExitEvent {
+            position: point(px(50.), px(25.)),
+            ..Default::default()
+        });
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 1.);
+    }
+
+    #[gpui::test]
+    fn test_group_hover_cleared_when_window_deactivates(cx: &mut TestAppContext) {
+        let opacity = Rc::new(Cell::new(0.));
+        let (_, cx) = cx.add_window_view(|_, _| two_group_hover_view(&opacity));
+        cx.update(|window, _| window.activate_window());
+        cx.run_until_parked();
+
+        cx.simulate_mouse_move(point(px(50.), px(25.)), None, Modifiers::none());
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 0.25);
+
+        cx.deactivate_window();
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 1.);
+
+        cx.update(|window, _| window.activate_window());
+        cx.simulate_mouse_move(point(px(50.), px(75.)), None, Modifiers::none());
+        cx.run_until_parked();
+        assert_eq!(opacity.get(), 0.5);
+    }
+
+    #[gpui::test]
+    fn test_nested_groups_sharing_a_name(cx: &mut TestAppContext) {
+        let outer_opacity = Rc::new(Cell::new(0.));
+        let inner_opacity = Rc::new(Cell::new(0.));
+        let (_, cx) = cx.add_window_view(|_, _| {
+            let outer_opacity = outer_opacity.clone();
+            let inner_opacity = inner_opacity.clone();
+            ElementView::new(move || {
+                div()
+                    .group("row")
+                    .size(px(100.))
+                    .child(
+                        div()
+                            .w(px(100.))
+                            .h(px(20.))
+                            .group_hover("row", |style| style.opacity(0.5))
+                            .child(opacity_probe(&outer_opacity)),
+                    )
+                    .child(
+                        div()
+                            .group("row")
+                            .absolute()
+                            .top(px(50.))
+                            .w(px(100.))
+                            .h(px(50.))
+                            .child(
+                                div()
+                                    .size_full()
+                                    .group_hover("row", |style| style.opacity(0.5))
+                                    .child(opacity_probe(&inner_opacity)),
+                            ),
+                    )
+            })
+        });
+
+        cx.simulate_mouse_move(point(px(10.), px(10.)), None, Modifiers::none());
+        cx.run_until_parked();
+        assert_eq!(outer_opacity.get(), 0.5);
+        assert_eq!(inner_opacity.get(), 1.);
+
+        cx.simulate_mouse_move(point(px(10.), px(70.)), None, Modifiers::none());
+        cx.run_until_parked();
+        assert_eq!(outer_opacity.get(), 0.5);
+        assert_eq!(inner_opacity.get(), 0.5);
+
+        cx.simulate_mouse_move(point(px(150.), px(150.)), None, Modifiers::none());
+        cx.run_until_parked();
+        assert_eq!(outer_opacity.get(), 1.);
+        assert_eq!(inner_opacity.get(), 1.);
+    }
+
+    struct TabOrderView {
+        focus_handles: [FocusHandle; 3],
+    }
+
+    impl Render for TabOrderView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            div()
+                .child(div().track_focus(&self.focus_handles[0]).tab_index(1))
+                .child(div().track_focus(&self.focus_handles[1]).tab_index(-1))
+                .child(div().track_focus(&self.focus_handles[2]).tab_index(0))
+        }
+    }
+
+    #[gpui::test]
+    fn test_tab_order_navigation(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_, cx| TabOrderView {
+            focus_handles: [cx.focus_handle(), cx.focus_handle(), cx.focus_handle()],
+        });
+        let focus_handles = cx.update(|_, cx| view.read(cx).focus_handles.clone());
+        let assert_focused = |ix: usize, cx: &mut gpui::VisualTestContext| {
+            cx.update(|window, _| assert!(focus_handles[ix].is_focused(window)));
+        };
+
+        cx.simulate_keystrokes("tab");
+        assert_focused(2, cx);
+        cx.simulate_keystrokes("tab");
+        assert_focused(0, cx);
+        cx.simulate_keystrokes("tab");
+        assert_focused(2, cx);
+        cx.simulate_keystrokes("shift-tab");
+        assert_focused(0, cx);
+    }
+
+    struct FocusTrapView {
+        show_trap: bool,
+        focus_handles: [FocusHandle; 4],
+    }
+
+    impl FocusTrapView {
+        fn new(cx: &mut Context<Self>) -> Self {
+            Self {
+                show_trap: true,
+                focus_handles: [
+                    cx.focus_handle(),
+                    cx.focus_handle(),
+                    cx.focus_handle(),
+                    cx.focus_handle(),
+                ],
+            }
+        }
+    }
+
+    impl Render for FocusTrapView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            div()
+                .child(div().track_focus(&self.focus_handles[0]).tab_index(0))
+                .when(self.show_trap, |this| {
+                    this.child(
+                        div()
+                            .track_focus(&self.focus_handles[1])
+                            .trap_focus()
+                            .child(div().track_focus(&self.focus_handles[2]).tab_index(0))
+                            .child(div().track_focus(&self.focus_handles[3]).tab_index(0)),
+                    )
+                })
+        }
+    }
+
+    #[gpui::test]
+    fn test_focus_trap(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_, cx| FocusTrapView::new(cx));
+        let focus_handles = cx.update(|_, cx| view.read(cx).focus_handles.clone());
+        let assert_focused = |ix: usize, cx: &mut gpui::VisualTestContext| {
+            cx.update(|window, _| assert!(focus_handles[ix].is_focused(window)));
+        };
+
+        cx.update(|window, _| window.focus(&focus_handles[2]));
+        cx.simulate_keystrokes("tab");
+        assert_focused(3, cx);
+        cx.simulate_keystrokes("tab");
+        assert_focused(2, cx);
+        cx.simulate_keystrokes("shift-tab");
+        assert_focused(3, cx);
+    }
+
+    #[gpui::test]
+    fn test_focus_trap_redirects_focus_moved_outside(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_, cx| FocusTrapView::new(cx));
+        let focus_handles = cx.update(|_, cx| view.read(cx).focus_handles.clone());
+        let focus = |ix: usize, cx: &mut gpui::VisualTestContext| {
+            cx.update(|window, _| window.focus(&focus_handles[ix]));
+            cx.run_until_parked();
+        };
+
+        focus(3, cx);
+        focus(0, cx);
+        cx.update(|window, _| assert!(focus_handles[3].is_focused(window)));
+    }
+
+    #[gpui::test]
+    fn test_focus_trap_restores_focus_on_unmount(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_, cx| FocusTrapView::new(cx));
+        let focus_handles = cx.update(|_, cx| view.read(cx).focus_handles.clone());
+        let focus = |ix: usize, cx: &mut gpui::VisualTestContext| {
+            cx.update(|window, _| window.focus(&focus_handles[ix]));
+            cx.run_until_parked();
+        };
+
+        focus(0, cx);
+        focus(2, cx);
+        view.update(cx, |view, cx| {
+            view.show_trap = false;
+            cx.notify();
+        });
+        cx.run_until_parked();
+        cx.update(|window, _| assert!(focus_handles[0].is_focused(window)));
+    }
+
+    struct MountFocusView {
+        show_child: bool,
+        focus_handles: [FocusHandle; 2],
+    }
+
+    impl Render for MountFocusView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            div()
+                .child(div().track_focus(&self.focus_handles[0]))
+                .children(
+                    self.show_child
+                        .then(|| div().track_focus(&self.focus_handles[1]).focus_on_mount()),
+                )
+        }
+    }
+
+    #[gpui::test]
+    fn test_focus_on_mount(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_, cx| MountFocusView {
+            show_child: false,
+            focus_handles: [cx.focus_handle(), cx.focus_handle()],
+        });
+        let focus_handles = cx.update(|_, cx| view.read(cx).focus_handles.clone());
+
+        view.update(cx, |view, cx| {
+            view.show_child = true;
+            cx.notify();
+        });
+        cx.run_until_parked();
+        cx.update(|window, _| assert!(focus_handles[1].is_focused(window)));
+
+        cx.update(|window, _| window.focus(&focus_handles[0]));
+        view.update(cx, |_, cx| cx.notify());
+        cx.run_until_parked();
+        cx.update(|window, _| assert!(focus_handles[0].is_focused(window)));
+    }
+
+    struct DragScrollView {
+        scroll_handle: ScrollHandle,
+        autoscroll: bool,
+    }
+
+    impl Render for DragScrollView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            div()
+                .id("list")
+                .w(px(100.))
+                .h(px(100.))
+                .flex()
+                .flex_col()
+                .overflow_y_scroll()
+                .track_scroll(&self.scroll_handle)
+                .when(self.autoscroll, |list| list.autoscroll_on_drag())
+                .children((0..20).map(|ix| {
+                    div()
+                        .id(ix)
+                        .flex_none()
+                        .h(px(10.))
+                        .on_drag(ix, |_, _, _, cx| cx.new(|_| EmptyView))
+                }))
+        }
+    }
+
+    #[gpui::test]
+    fn test_drag_autoscroll_near_edge(cx: &mut TestAppContext) {
+        let scroll_handle = ScrollHandle::new();
+        let (_, cx) = cx.add_window_view(|_, _| DragScrollView {
+            scroll_handle: scroll_handle.clone(),
+            autoscroll: true,
+        });
+
+        cx.simulate_mouse_down(point(px(10.), px(50.)), MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_move(point(px(10.), px(60.)), MouseButton::Left, Modifiers::none());
+        assert_eq!(scroll_handle.offset().y, px(0.));
+
+        cx.simulate_mouse_move(point(px(10.), px(95.)), MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_move(point(px(10.), px(95.)), MouseButton::Left, Modifiers::none());
+        let offset = scroll_handle.offset().y;
+        assert!(offset < px(0.));
+
+        cx.simulate_mouse_up(point(px(10.), px(95.)), MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_move(point(px(10.), px(95.)), None, Modifiers::none());
+        cx.simulate_mouse_move(point(px(10.), px(95.)), None, Modifiers::none());
+        assert_eq!(scroll_handle.offset().y, offset);
+    }
+
+    #[gpui::test]
+    fn test_drag_autoscroll_is_opt_in(cx: &mut TestAppContext) {
+        let scroll_handle = ScrollHandle::new();
+        let (_, cx) = cx.add_window_view(|_, _| DragScrollView {
+            scroll_handle: scroll_handle.clone(),
+            autoscroll: false,
+        });
+
+        cx.simulate_mouse_down(point(px(10.), px(50.)), MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_move(point(px(10.), px(95.)), MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_move(point(px(10.), px(95.)), MouseButton::Left, Modifiers::none());
+        assert_eq!(scroll_handle.offset().y, px(0.));
+    }
+
+    struct NestedScrollView {
+        outer: ScrollHandle,
+        inner: ScrollHandle,
+        clip_inner: bool,
+    }
+
+    impl Render for NestedScrollView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            div()
+                .id("outer")
+                .w(px(100.))
+                .h(px(40.))
+                .flex()
+                .flex_col()
+                .overflow_y_scroll()
+                .track_scroll(&self.outer)
+                .child(
+                    div()
+                        .id("inner")
+                        .flex_none()
+                        .w(px(100.))
+                        .h(px(20.))
+                        .flex()
+                        .flex_col()
+                        .map(|inner| {
+                            if self.clip_inner {
+                                inner.overflow_y_clip()
+                            } else {
+                                inner.overflow_y_scroll()
+                            }
+                        })
+                        .track_scroll(&self.inner)
+                        .children((0..3).map(|_| div().flex_none().h(px(10.)))),
+                )
+                .children((0..4).map(|_| div().flex_none().h(px(10.))))
+        }
+    }
+
+    fn scroll_down(cx: &mut gpui::VisualTestContext) {
+        cx.simulate_event(ScrollWheelEvent {
+            position: point(px(10.), px(10.)),
+            delta: ScrollDelta::Pixels(point(px(0.), px(-5.))),
+            ..Default::default()
+        });
+    }
+
+    #[gpui::test]
+    fn test_nested_scroll_chains_at_edge(cx: &mut TestAppContext) {
+        let outer = ScrollHandle::new();
+        let inner = ScrollHandle::new();
+        let (_, cx) = cx.add_window_view(|_, _| NestedScrollView {
+            outer: outer.clone(),
+            inner: inner.clone(),
+            clip_inner: false,
+        });
+
+        scroll_down(cx);
+        assert_eq!(inner.offset().y, px(-5.));
+        assert_eq!(outer.offset().y, px(0.));
+
+        inner.set_offset(point(px(0.), px(-10.)));
+        scroll_down(cx);
+        assert_eq!(inner.offset().y, px(-10.));
+        assert_eq!(outer.offset().y, px(-5.));
+    }
+
+    #[gpui::test]
+    fn test_clip_container_ignores_scroll_wheel(cx: &mut TestAppContext) {
+        let outer = ScrollHandle::new();
+        let inner = ScrollHandle::new();
+        let (_, cx) = cx.add_window_view(|_, _| NestedScrollView {
+            outer: outer.clone(),
+            inner: inner.clone(),
+            clip_inner: true,
+        });
+
+        scroll_down(cx);
+        assert_eq!(inner.offset().y, px(0.));
+        assert_eq!(outer.offset().y, px(-5.));
+    }
+
+    struct HorizontalInVerticalScrollView {
+        outer: ScrollHandle,
+        inner: ScrollHandle,
+        inner_wheel_events: Rc<Cell<usize>>,
+    }
+
+    impl Render for HorizontalInVerticalScrollView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let inner_wheel_events = self.inner_wheel_events.clone();
+            div()
+                .id("outer")
+                .w(px(100.))
+                .h(px(40.))
+                .flex()
+                .flex_col()
+                .overflow_y_scroll()
+                .track_scroll(&self.outer)
+                .child(
+                    div()
+                        .id("inner")
+                        .flex_none()
+                        .w(px(100.))
+                        .h(px(20.))
+                        .flex()
+                        .overflow_x_scroll()
+                        .track_scroll(&self.inner)
+                        .on_scroll_wheel(move |_, _, _| {
+                            inner_wheel_events.set(inner_wheel_events.get() + 1)
+                        })
+                        .children((0..3).map(|_| div().flex_none().w(px(50.)))),
+                )
+                .children((0..4).map(|_| div().flex_none().h(px(10.))))
+        }
+    }
+
+    #[gpui::test]
+    fn test_remapped_wheel_keeps_chaining(cx: &mut TestAppContext) {
+        let outer = ScrollHandle::new();
+        let inner = ScrollHandle::new();
+        let inner_wheel_events = Rc::new(Cell::new(0));
+        let (_, cx) = cx.add_window_view(|_, _| HorizontalInVerticalScrollView {
+            outer: outer.clone(),
+            inner: inner.clone(),
+            inner_wheel_events: inner_wheel_events.clone(),
+        });
+
+        scroll_down(cx);
+        assert_eq!(inner.offset().x, px(-5.));
+        assert_eq!(outer.offset().y, px(-5.));
+        assert_eq!(inner_wheel_events.get(), 1);
+    }
+}
@@ -482,9 +482,17 @@ impl PlatformInput {
 #[cfg(test)]
 mod test {
 
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+
     use crate::{
-        self as gpui, AppContext as _, Context, FocusHandle, InteractiveElement, IntoElement,
-        KeyBinding, Keystroke, ParentElement, Render, TestAppContext, Window, div,
+        self as gpui, App, AppContext as _, Bounds, Context, DispatchPhase, Element, ElementId,
+        FocusHandle, GlobalElementId, Hitbox, HitboxBehavior, InspectorElementId,
+        InteractiveElement, IntoElement, KeyBinding, Keystroke, LayoutId, Modifiers, MouseButton,
+        MouseDownEvent, MouseExitEvent, MouseMoveEvent, ParentElement, Pixels, Render,
+        StatefulInteractiveElement, Style, Styled, TestAppContext, Window, div, point, px, size,
     };
 
     struct TestView {
@@ -551,4 +559,246 @@ mod test {
             })
             .unwrap();
     }
+
+    struct CaptureElement {
+        hovered_moves: Rc<Cell<usize>>,
+        paints: Rc<Cell<usize>>,
+    }
+
+    impl IntoElement for CaptureElement {
+        type Element = Self;
+
+        fn into_element(self) -> Self::Element {
+            self
+        }
+    }
+
+    impl Element for CaptureElement {
+        type RequestLayoutState = ();
+        type PrepaintState = Hitbox;
+
+        fn id(&self) -> Option<ElementId> {
+            Some("capture".into())
+        }
+
+        fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+            None
+        }
+
+        fn request_layout(
+            &mut self,
+            _: Option<&GlobalElementId>,
+            _: Option<&InspectorElementId>,
+            window: &mut Window,
+            cx: &mut App,
+        ) -> (LayoutId, ()) {
+            let style = Style {
+                size: size(px(10.).into(), px(10.).into()),
+                ..Style::default()
+            };
+            (window.request_layout(style, [], cx), ())
+        }
+
+        fn prepaint(
+            &mut self,
+            _: Option<&GlobalElementId>,
+            _: Option<&InspectorElementId>,
+            bounds: Bounds<Pixels>,
+            _: &mut (),
+            window: &mut Window,
+            _: &mut App,
+        ) -> Hitbox {
+            window.insert_hitbox(bounds, HitboxBehavior::Normal)
+        }
+
+        fn paint(
+            &mut self,
+            id: Option<&GlobalElementId>,
+            _: Option<&InspectorElementId>,
+            _: Bounds<Pixels>,
+            _: &mut (),
+            hitbox: &mut Hitbox,
+            window: &mut Window,
+            _: &mut App,
+        ) {
+            self.paints.set(self.paints.get() + 1);
+            let element_id = id.cloned().unwrap();
+            window.on_mouse_event({
+                let hitbox = hitbox.clone();
+                move |event: &MouseDownEvent, phase, window, _| {
+                    if phase == DispatchPhase::Bubble && hitbox.is_hovered(window) {
+                        window.capture_pointer(&element_id, hitbox.id, event.button);
+                    }
+                }
+            });
+            let hitbox = hitbox.clone();
+            let hovered_moves = self.hovered_moves.clone();
+            window.on_mouse_event(move |_: &MouseMoveEvent, phase, window, _| {
+                if phase == DispatchPhase::Bubble && hitbox.is_hovered(window) {
+                    hovered_moves.set(hovered_moves.get() + 1);
+                }
+            });
+        }
+    }
+
+    struct CaptureView {
+        hovered_moves: Rc<Cell<usize>>,
+        bystander_moves: Rc<Cell<usize>>,
+        paints: Rc<Cell<usize>>,
+    }
+
+    impl Render for CaptureView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let bystander_moves = self.bystander_moves.clone();
+            div()
+                .size_full()
+                .on_mouse_move(move |_, _, _| bystander_moves.set(bystander_moves.get() + 1))
+                .child(CaptureElement {
+                    hovered_moves: self.hovered_moves.clone(),
+                    paints: self.paints.clone(),
+                })
+        }
+    }
+
+    #[gpui::test]
+    fn test_pointer_capture(cx: &mut TestAppContext) {
+        let hovered_moves = Rc::new(Cell::new(0));
+        let bystander_moves = Rc::new(Cell::new(0));
+        let paints = Rc::new(Cell::new(0));
+        let (view, cx) = cx.add_window_view(|_, _| CaptureView {
+            hovered_moves: hovered_moves.clone(),
+            bystander_moves: bystander_moves.clone(),
+            paints: paints.clone(),
+        });
+
+        cx.simulate_mouse_move(point(px(50.), px(50.)), None, Modifiers::none());
+        assert_eq!(hovered_moves.get(), 0);
+        assert_eq!(bystander_moves.get(), 1);
+
+        cx.simulate_mouse_down(point(px(5.), px(5.)), MouseButton::Left, Modifiers::none());
+        // Redraw so the capturing element gets a new hitbox before the pointer moves away.
+        let paints_before_redraw = paints.get();
+        view.update(cx, |_, cx| cx.notify());
+        cx.run_until_parked();
+        assert!(paints.get() > paints_before_redraw);
+
+        cx.simulate_mouse_move(
+            point(px(50.), px(50.)),
+            MouseButton::Left,
+            Modifiers::none(),
+        );
+        assert_eq!(hovered_moves.get(), 1);
+        // Only the capturing element sees the pointer, even though the parent is under it.
+        assert_eq!(bystander_moves.get(), 1);
+
+        // Releasing another button keeps the capture.
+        cx.simulate_mouse_down(point(px(50.), px(50.)), MouseButton::Right, Modifiers::none());
+        cx.simulate_mouse_up(point(px(50.), px(50.)), MouseButton::Right, Modifiers::none());
+        cx.simulate_mouse_move(
+            point(px(55.), px(55.)),
+            MouseButton::Left,
+            Modifiers::none(),
+        );
+        assert_eq!(hovered_moves.get(), 2);
+        assert_eq!(bystander_moves.get(), 1);
+
+        cx.simulate_mouse_up(point(px(55.), px(55.)), MouseButton::Left, Modifiers::none());
+        cx.simulate_mouse_move(point(px(60.), px(60.)), None, Modifiers::none());
+        assert_eq!(hovered_moves.get(), 2);
+        assert_eq!(bystander_moves.get(), 2);
+    }
+
+    struct PressView {
+        clicks: Rc<Cell<usize>>,
+        inner_moves: Rc<Cell<usize>>,
+        sibling_moves: Rc<Cell<usize>>,
+    }
+
+    impl Render for PressView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let clicks = self.clicks.clone();
+            let inner_moves = self.inner_moves.clone();
+            let sibling_moves = self.sibling_moves.clone();
+            div()
+                .size_full()
+                .child(
+                    div()
+                        .id("pressable")
+                        .size(px(20.))
+                        .on_click(move |_, _, _| clicks.set(clicks.get() + 1))
+                        .child(div().size(px(10.)).on_mouse_move(move |_, _, _| {
+                            inner_moves.set(inner_moves.get() + 1)
+                        })),
+                )
+                .child(
+                    div()
+                        .size(px(20.))
+                        .on_mouse_move(move |_, _, _| sibling_moves.set(sibling_moves.get() + 1)),
+                )
+        }
+    }
+
+    #[gpui::test]
+    fn test_div_press_captures_pointer(cx: &mut TestAppContext) {
+        let clicks = Rc::new(Cell::new(0));
+        let inner_moves = Rc::new(Cell::new(0));
+        let sibling_moves = Rc::new(Cell::new(0));
+        let (_, cx) = cx.add_window_view(|_, _| PressView {
+            clicks: clicks.clone(),
+            inner_moves: inner_moves.clone(),
+            sibling_moves: sibling_moves.clone(),
+        });
+
+        cx.simulate_mouse_down(point(px(5.), px(5.)), MouseButton::Left, Modifiers::none());
+        // Descendants of the pressed element still see the pointer.
+        cx.simulate_mouse_move(point(px(5.), px(6.)), MouseButton::Left, Modifiers::none());
+        assert_eq!(inner_moves.get(), 1);
+        cx.simulate_mouse_move(point(px(5.), px(30.)), MouseButton::Left, Modifiers::none());
+        assert_eq!(sibling_moves.get(), 0);
+
+        cx.simulate_mouse_up(point(px(5.), px(30.)), MouseButton::Left, Modifiers::none());
+        assert_eq!(clicks.get(), 0);
+        cx.simulate_mouse_move(point(px(5.), px(31.)), None, Modifiers::none());
+        assert_eq!(sibling_moves.get(), 1);
+
+        cx.simulate_click(point(px(5.), px(5.)), Modifiers::none());
+        assert_eq!(clicks.get(), 1);
+    }
+
+    struct HoverView {
+        hover_events: Rc<RefCell<Vec<bool>>>,
+    }
+
+    impl Render for HoverView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let hover_events = self.hover_events.clone();
+            div()
+                .id("hoverable")
+                .size(px(10.))
+                .on_hover(move |hovered, _, _| hover_events.borrow_mut().push(*hovered))
+        }
+    }
+
+    #[gpui::test]
+    fn test_mouse_exit_clears_hover(cx: &mut TestAppContext) {
+        let hover_events = Rc::new(RefCell::new(Vec::new()));
+        let (_, cx) = cx.add_window_view(|_, _| HoverView {
+            hover_events: hover_events.clone(),
+        });
+
+        cx.simulate_mouse_move(point(px(5.), px(5.)), None, Modifiers::none());
+        assert_eq!(*hover_events.borrow(), [true]);
+
+        cx.simulate_event(MouseExitEvent {
+            position: point(px(5.), px(5.)),
+            ..Default::default()
+        });
+        assert_eq!(*hover_events.borrow(), [true, false]);
+        cx.update(|window, _| window.refresh());
+        cx.run_until_parked();
+        assert_eq!(*hover_events.borrow(), [true, false]);
+
+        cx.simulate_mouse_move(point(px(5.), px(5.)), None, Modifiers::none());
+        assert_eq!(*hover_events.borrow(), [true, false, true]);
+    }
 }
@@ -9,6 +9,7 @@ pub use gpui_macros::{
     overflow_style_methods, padding_style_methods, position_style_methods,
     visibility_style_methods,
 };
+use refineable::Refineable;
 
 const ELLIPSIS: SharedString = SharedString::new_static("…");
 
@@ -354,6 +355,22 @@ pub trait Styled: Sized {
         self
     }
 
+    /// Sets the preferred aspect ratio of the element, calculated as width divided by height.
+    /// When only one dimension is determined by the layout, the other is derived from it.
+    /// If both dimensions are already definite, the aspect ratio is ignored.
+    /// [Docs](https://tailwindcss.com/docs/aspect-ratio)
+    fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.style().aspect_ratio = Some(ratio);
+        self
+    }
+
+    /// Sets the aspect ratio of the element to 1 / 1.
+    /// [Docs](https://tailwindcss.com/docs/aspect-ratio)
+    fn aspect_square(mut self) -> Self {
+        self.style().aspect_ratio = Some(1.);
+        self
+    }
+
     /// Sets the background color of the element.
     fn bg<F>(mut self, fill: F) -> Self
     where
@@ -640,6 +657,22 @@ pub trait Styled: Sized {
         self
     }
 
+    /// Merges the given style refinement into this element's style, such as one prepared
+    /// from theme values.
+    fn refine_style(mut self, refinement: &StyleRefinement) -> Self {
+        self.style().refine(refinement);
+        self
+    }
+
+    /// Merges the given style refinement into this element's style if the condition is true.
+    fn refine_style_if(self, condition: bool, refinement: &StyleRefinement) -> Self {
+        if condition {
+            self.refine_style(refinement)
+        } else {
+            self
+        }
+    }
+
     /// Draws a debug border around this element.
     #[cfg(debug_assertions)]
     fn debug(mut self) -> Self {
@@ -654,3 +687,44 @@ pub trait Styled: Sized {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use crate::{
+        self as gpui, Bounds, ParentElement, Pixels, StyleRefinement, Styled, TestAppContext,
+        canvas, div, point, px, size,
+    };
+
+    #[gpui::test]
+    fn test_aspect_ratio_derives_height_from_width(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let bounds = Rc::new(Cell::new(Bounds::<Pixels>::default()));
+
+        cx.draw(point(px(0.), px(0.)), size(px(500.), px(500.)), |_, _| {
+            let bounds = bounds.clone();
+            div().w(px(320.)).child(
+                div().w_full().aspect_ratio(16. / 9.).child(
+                    canvas(move |child_bounds, _, _| bounds.set(child_bounds), |_, _, _, _| {})
+                        .size_full(),
+                ),
+            )
+        });
+
+        assert_eq!(bounds.get().size, size(px(320.), px(180.)));
+    }
+
+    #[test]
+    fn test_refine_style_if() {
+        let refinement = StyleRefinement::default().opacity(0.5).flex();
+
+        let mut unchanged = div().refine_style_if(false, &refinement);
+        assert_eq!(unchanged.style().opacity, None);
+        assert_eq!(unchanged.style().display, None);
+
+        let mut refined = div().refine_style_if(true, &refinement);
+        assert_eq!(refined.style().opacity, Some(0.5));
+        assert_eq!(refined.style().display, Some(gpui::Display::Flex));
+    }
+}
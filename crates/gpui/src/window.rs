@@ -8,14 +8,15 @@ use crate::{
     FileDropEvent, FontId, Global, GlobalElementId, GlyphId, GpuSpecs, Hsla, InputHandler, IsZero,
     KeyBinding, KeyContext, KeyDownEvent, KeyEvent, Keystroke, KeystrokeEvent, LayoutId,
     LineLayoutIndex, Modifiers, ModifiersChangedEvent, MonochromeSprite, MouseButton, MouseEvent,
-    MouseMoveEvent, MouseUpEvent, Path, Pixels, PlatformAtlas, PlatformDisplay, PlatformInput,
-    PlatformInputHandler, PlatformWindow, Point, PolychromeSprite, PromptButton, PromptLevel, Quad,
-    Render, RenderGlyphParams, RenderImage, RenderImageParams, RenderSvgParams, Replay, ResizeEdge,
-    SMOOTH_SVG_SCALE_FACTOR, SUBPIXEL_VARIANTS, ScaledPixels, Scene, Shadow, SharedString, Size,
-    StrikethroughStyle, Style, SubscriberSet, Subscription, TaffyLayoutEngine, Task, TextStyle,
-    TextStyleRefinement, TransformationMatrix, Underline, UnderlineStyle, WindowAppearance,
-    WindowBackgroundAppearance, WindowBounds, WindowControls, WindowDecorations, WindowOptions,
-    WindowParams, WindowTextSystem, point, prelude::*, px, rems, size, transparent_black,
+    MouseExitEvent, MouseMoveEvent, MouseUpEvent, Path, Pixels, PlatformAtlas, PlatformDisplay,
+    PlatformInput, PlatformInputHandler, PlatformWindow, Point, PolychromeSprite, PromptButton,
+    PromptLevel, Quad, Render, RenderGlyphParams, RenderImage, RenderImageParams, RenderSvgParams,
+    Replay, ResizeEdge, SMOOTH_SVG_SCALE_FACTOR, SUBPIXEL_VARIANTS, ScaledPixels, Scene, Shadow,
+    SharedString, Size, StrikethroughStyle, Style, SubscriberSet, Subscription, TaffyLayoutEngine,
+    Task, TextStyle, TextStyleRefinement, TransformationMatrix, Underline, UnderlineStyle,
+    WindowAppearance, WindowBackgroundAppearance, WindowBounds, WindowControls, WindowDecorations,
+    WindowOptions, WindowParams, WindowTextSystem, point, prelude::*, px, rems, size,
+    transparent_black,
 };
 use anyhow::{Context as _, Result, anyhow};
 use collections::{FxHashMap, FxHashSet};
@@ -418,6 +419,8 @@ pub(crate) struct CursorStyleRequest {
 pub(crate) struct HitTest {
     pub(crate) ids: SmallVec<[HitboxId; 8]>,
     pub(crate) hover_hitbox_count: usize,
+    /// The hitboxes that count as hovered while the pointer is captured.
+    pub(crate) captured_hover: Option<SmallVec<[HitboxId; 8]>>,
 }
 
 /// A type of window control area that corresponds to the platform window.
@@ -444,6 +447,16 @@ impl HitboxId {
     ///
     /// See [`Hitbox::is_hovered`] for details.
     pub fn is_hovered(self, window: &Window) -> bool {
+        if let Some(captured_hover) = window.mouse_hit_test.captured_hover.as_ref() {
+            return captured_hover.contains(&self);
+        }
+        self.is_hovered_ignoring_capture(window)
+    }
+
+    /// Checks if the hitbox with this ID is under the mouse, disregarding any pointer capture.
+    ///
+    /// See [`Hitbox::is_hovered_ignoring_capture`] for details.
+    pub fn is_hovered_ignoring_capture(self, window: &Window) -> bool {
         let hit_test = &window.mouse_hit_test;
         for id in hit_test.ids.iter().take(hit_test.hover_hitbox_count) {
             if self == *id {
@@ -479,6 +492,9 @@ pub struct Hitbox {
     pub content_mask: ContentMask<Pixels>,
     /// Flags that specify hitbox behavior.
     pub behavior: HitboxBehavior,
+    /// How many hitboxes, starting with this one, the inserting element and its descendants
+    /// inserted. Zero when the element inserted other hitboxes before this one.
+    pub(crate) subtree_len: usize,
 }
 
 impl Hitbox {
@@ -500,6 +516,14 @@ impl Hitbox {
         self.id.is_hovered(window)
     }
 
+    /// Checks if the hitbox is under the mouse, as [`Hitbox::is_hovered`] would without a pointer
+    /// capture. While [`Window::capture_pointer`] is in effect, `is_hovered` reports the capturing
+    /// hitbox as hovered wherever the mouse is, and hitboxes outside the capturing element as not
+    /// hovered. Use this to check whether a captured press is released over its element.
+    pub fn is_hovered_ignoring_capture(&self, window: &Window) -> bool {
+        self.id.is_hovered_ignoring_capture(window)
+    }
+
     /// Checks if the hitbox contains the mouse and should handle scroll events. Typically this
     /// should only be used when handling `ScrollWheelEvent`, and otherwise `is_hovered` should be
     /// used. See the documentation of `Hitbox::is_hovered` for details about this distinction.
@@ -624,6 +648,10 @@ pub(crate) struct Frame {
     pub(crate) input_handlers: Vec<Option<PlatformInputHandler>>,
     pub(crate) tooltip_requests: Vec<Option<TooltipRequest>>,
     pub(crate) cursor_styles: Vec<CursorStyleRequest>,
+    pub(crate) tab_stops: Vec<(isize, FocusHandle)>,
+    pub(crate) captured_hitbox: Option<HitboxId>,
+    pub(crate) focus_traps: Vec<FocusHandle>,
+    pub(crate) mount_focus: Option<FocusHandle>,
     #[cfg(any(test, feature = "test-support"))]
     pub(crate) debug_bounds: FxHashMap<String, Bounds<Pixels>>,
     #[cfg(any(feature = "inspector", debug_assertions))]
@@ -648,6 +676,8 @@ pub(crate) struct PaintIndex {
     mouse_listeners_index: usize,
     input_handlers_index: usize,
     cursor_styles_index: usize,
+    tab_stops_index: usize,
+    focus_traps_index: usize,
     accessed_element_states_index: usize,
     line_layout_index: LineLayoutIndex,
 }
@@ -668,6 +698,10 @@ impl Frame {
             input_handlers: Vec::new(),
             tooltip_requests: Vec::new(),
             cursor_styles: Vec::new(),
+            tab_stops: Vec::new(),
+            captured_hitbox: None,
+            focus_traps: Vec::new(),
+            mount_focus: None,
 
             #[cfg(any(test, feature = "test-support"))]
             debug_bounds: FxHashMap::default(),
@@ -689,6 +723,10 @@ impl Frame {
         self.input_handlers.clear();
         self.tooltip_requests.clear();
         self.cursor_styles.clear();
+        self.tab_stops.clear();
+        self.captured_hitbox = None;
+        self.focus_traps.clear();
+        self.mount_focus = None;
         self.hitboxes.clear();
         self.window_control_hitboxes.clear();
         self.deferred_draws.clear();
@@ -738,6 +776,34 @@ impl Frame {
         hit_test
     }
 
+    /// The hitboxes hovered while `captured_hitbox` captures the pointer: the captured hitbox
+    /// itself, and the hitboxes under the mouse that its element's descendants inserted.
+    fn captured_hover(
+        &self,
+        captured_hitbox: HitboxId,
+        hit_test: &HitTest,
+    ) -> SmallVec<[HitboxId; 8]> {
+        let mut hovered = SmallVec::new();
+        hovered.push(captured_hitbox);
+        if let Some(ix) = self
+            .hitboxes
+            .iter()
+            .position(|hitbox| hitbox.id == captured_hitbox)
+        {
+            let end = (ix + self.hitboxes[ix].subtree_len).min(self.hitboxes.len());
+            let descendants = self.hitboxes.get(ix + 1..end).unwrap_or_default();
+            hovered.extend(
+                hit_test
+                    .ids
+                    .iter()
+                    .take(hit_test.hover_hitbox_count)
+                    .filter(|id| descendants.iter().any(|hitbox| hitbox.id == **id))
+                    .copied(),
+            );
+        }
+        hovered
+    }
+
     pub(crate) fn focus_path(&self) -> SmallVec<[FocusId; 8]> {
         self.focus
             .map(|focus_id| self.dispatch_tree.focus_path(focus_id))
@@ -793,8 +859,13 @@ pub struct Window {
     focus_listeners: SubscriberSet<(), AnyWindowFocusListener>,
     pub(crate) focus_lost_listeners: SubscriberSet<(), AnyObserver>,
     default_prevented: bool,
+    /// The axes along which a scroll container has already moved for the wheel event being
+    /// dispatched.
+    pub(crate) consumed_scroll_axes: Point<bool>,
     mouse_position: Point<Pixels>,
     mouse_hit_test: HitTest,
+    mouse_exited: bool,
+    pointer_capture: Option<PointerCapture>,
     modifiers: Modifiers,
     capslock: Capslock,
     scale_factor: f32,
@@ -809,6 +880,10 @@ pub struct Window {
     pub(crate) activation_observers: SubscriberSet<(), AnyObserver>,
     pub(crate) focus: Option<FocusId>,
     focus_enabled: bool,
+    /// For each mounted focus trap holding focus, the handle that was focused before focus
+    /// entered it.
+    focus_trap_restores: FxHashMap<FocusId, WeakFocusHandle>,
+    pub(crate) focus_visible: bool,
     pending_input: Option<PendingInput>,
     pending_modifier: ModifierState,
     pub(crate) pending_input_observers: SubscriberSet<(), AnyObserver>,
@@ -818,6 +893,11 @@ pub struct Window {
     inspector: Option<Entity<Inspector>>,
 }
 
+struct PointerCapture {
+    element_id: GlobalElementId,
+    button: MouseButton,
+}
+
 #[derive(Clone, Debug, Default)]
 struct ModifierState {
     modifiers: Modifiers,
@@ -1022,6 +1102,19 @@ impl Window {
                             .activation_observers
                             .clone()
                             .retain(&(), |callback| callback(window, cx));
+                        if !active {
+                            // A press or hover in progress when another window takes focus may
+                            // never see its release or exit, so treat the pointer as having left.
+                            window.release_pointer();
+                            window.dispatch_event(
+                                PlatformInput::MouseExited(MouseExitEvent {
+                                    position: window.mouse_position,
+                                    pressed_button: None,
+                                    modifiers: window.modifiers,
+                                }),
+                                cx,
+                            );
+                        }
                         window.refresh();
                     })
                     .log_err();
@@ -1100,8 +1193,11 @@ impl Window {
             focus_listeners: SubscriberSet::new(),
             focus_lost_listeners: SubscriberSet::new(),
             default_prevented: true,
+            consumed_scroll_axes: Point::default(),
             mouse_position,
             mouse_hit_test: HitTest::default(),
+            mouse_exited: false,
+            pointer_capture: None,
             modifiers,
             capslock,
             scale_factor,
@@ -1116,6 +1212,8 @@ impl Window {
             activation_observers: SubscriberSet::new(),
             focus: None,
             focus_enabled: true,
+            focus_trap_restores: FxHashMap::default(),
+            focus_visible: true,
             pending_input: None,
             pending_modifier: ModifierState::default(),
             pending_input_observers: SubscriberSet::new(),
@@ -1255,10 +1353,57 @@ impl Window {
         }
 
         self.focus = Some(handle.id);
+        self.focus_visible = true;
         self.clear_pending_keystrokes();
         self.refresh();
     }
 
+    /// Move focus to the next tab stop registered in the last frame, wrapping around at the end.
+    pub fn focus_next(&mut self) {
+        self.focus_tab_stop(true);
+    }
+
+    /// Move focus to the previous tab stop registered in the last frame, wrapping around at the
+    /// start.
+    pub fn focus_prev(&mut self) {
+        self.focus_tab_stop(false);
+    }
+
+    fn focus_tab_stop(&mut self, forward: bool) -> bool {
+        // Traps are painted outside-in, so the last one containing focus is the innermost.
+        let focus_trap = self.focus.and_then(|focus| {
+            self.rendered_frame
+                .focus_traps
+                .iter()
+                .rev()
+                .find(|trap| trap.id.contains(focus, self))
+        });
+        let mut tab_stops = self
+            .rendered_frame
+            .tab_stops
+            .iter()
+            .filter(|(_, handle)| focus_trap.is_none_or(|trap| trap.contains(handle, self)))
+            .collect::<Vec<_>>();
+        tab_stops.sort_by_key(|(index, _)| *index);
+        let count = tab_stops.len();
+        if count == 0 {
+            return false;
+        }
+
+        let current = self
+            .focus
+            .and_then(|focus| tab_stops.iter().position(|(_, handle)| handle.id == focus));
+        let next = match current {
+            Some(ix) if forward => (ix + 1) % count,
+            Some(ix) => (ix + count - 1) % count,
+            None if forward => 0,
+            None => count - 1,
+        };
+        let focus_handle = tab_stops[next].1.clone();
+        self.focus(&focus_handle);
+        true
+    }
+
     /// Remove focus from all elements within this context's window.
     pub fn blur(&mut self) {
         if !self.focus_enabled {
@@ -1713,6 +1858,45 @@ impl Window {
         self.mouse_position
     }
 
+    /// Routes mouse events to the given element until `button` is released or
+    /// [`Window::release_pointer`] is called. While captured, the element's hitbox is reported as
+    /// hovered wherever the mouse is. Hitboxes of its descendants are hovered as usual, and all
+    /// other hitboxes are not, so elements outside the capturing one ignore the pointer.
+    ///
+    /// `hitbox_id` is the element's hitbox in the current frame. On later frames, the capture
+    /// follows the first hitbox the element inserts, and ends if the element is no longer drawn.
+    /// The capture applies from the next mouse event on. If the pointer is already captured, this
+    /// does nothing, so the frontmost element handling a mouse down keeps the pointer.
+    pub fn capture_pointer(
+        &mut self,
+        element_id: &GlobalElementId,
+        hitbox_id: HitboxId,
+        button: MouseButton,
+    ) {
+        if self.pointer_capture.is_some() {
+            return;
+        }
+        self.pointer_capture = Some(PointerCapture {
+            element_id: element_id.clone(),
+            button,
+        });
+        self.rendered_frame.captured_hitbox = Some(hitbox_id);
+    }
+
+    /// Stops routing mouse events to the element passed to [`Window::capture_pointer`].
+    pub fn release_pointer(&mut self) {
+        if self.pointer_capture.take().is_some() {
+            self.rendered_frame.captured_hitbox = None;
+            self.mouse_hit_test = self.hit_test_at_mouse(&self.rendered_frame);
+            self.refresh();
+        }
+    }
+
+    /// The hitbox currently capturing the pointer, if any.
+    pub fn captured_hitbox(&self) -> Option<HitboxId> {
+        self.rendered_frame.captured_hitbox
+    }
+
     /// The current state of the keyboard's modifiers
     pub fn modifiers(&self) -> Modifiers {
         self.modifiers
@@ -1736,6 +1920,7 @@ impl Window {
         debug_assert!(self.rendered_entity_stack.is_empty());
         self.invalidator.set_dirty(false);
         self.requested_autoscroll = None;
+        let focus_before_draw = self.focus;
 
         // Restore the previously-used input handler.
         if let Some(input_handler) = self.platform_window.take_input_handler() {
@@ -1762,11 +1947,29 @@ impl Window {
             element_arena.clear();
         });
 
+        let mount_focus = self.next_frame.mount_focus.take();
         self.invalidator.set_phase(DrawPhase::Focus);
+        let previous_focus = self.rendered_frame.focus;
+        // Traps are painted outside-in, so the last one containing focus is the innermost.
+        let previous_focus_traps = previous_focus
+            .map(|focus| {
+                self.rendered_frame
+                    .focus_traps
+                    .iter()
+                    .filter(|trap| trap.id.contains(focus, self))
+                    .map(|trap| trap.id)
+                    .collect::<SmallVec<[FocusId; 2]>>()
+            })
+            .unwrap_or_default();
         let previous_focus_path = self.rendered_frame.focus_path();
         let previous_window_active = self.rendered_frame.window_active;
         mem::swap(&mut self.rendered_frame, &mut self.next_frame);
         self.next_frame.clear();
+        // The capturing element wasn't drawn in the new frame, so nothing can handle the capture.
+        if self.rendered_frame.captured_hitbox.is_none() {
+            self.pointer_capture = None;
+        }
+        let trap_focus = self.update_focus_traps(previous_focus, &previous_focus_traps, cx);
         let current_focus_path = self.rendered_frame.focus_path();
         let current_window_active = self.rendered_frame.window_active;
 
@@ -1802,6 +2005,78 @@ impl Window {
         self.refreshing = false;
         self.invalidator.set_phase(DrawPhase::None);
         self.needs_present.set(true);
+
+        if let Some(focus_handle) = trap_focus {
+            self.focus(&focus_handle);
+        }
+        if let Some(focus_handle) = mount_focus {
+            if self.focus == focus_before_draw {
+                self.focus(&focus_handle);
+            }
+        }
+    }
+
+    /// Keeps focus inside the innermost trap that held it in the previous frame, and hands focus
+    /// back to where it was before a trap took it once that trap unmounts. Returns the handle
+    /// that should be focused as a result, if any.
+    fn update_focus_traps(
+        &mut self,
+        previous_focus: Option<FocusId>,
+        previous_focus_traps: &[FocusId],
+        cx: &App,
+    ) -> Option<FocusHandle> {
+        let traps = &self.rendered_frame.focus_traps;
+        let trap_index = |trap_id: FocusId| traps.iter().position(|trap| trap.id == trap_id);
+        let previous_focus_handle =
+            previous_focus.and_then(|focus| FocusHandle::for_id(focus, &cx.focus_handles));
+        let mut refocus = None;
+
+        // Only the outermost unmounted trap knows where focus was before the whole stack of
+        // traps took it.
+        if self.focus.is_none() || self.focus == previous_focus {
+            refocus = previous_focus_traps
+                .iter()
+                .filter(|trap_id| trap_index(**trap_id).is_none())
+                .find_map(|trap_id| self.focus_trap_restores.get(trap_id))
+                .and_then(|restore| restore.upgrade());
+        }
+
+        if let Some((active_trap_index, focus)) = previous_focus_traps
+            .last()
+            .and_then(|trap_id| trap_index(*trap_id))
+            .zip(self.focus)
+        {
+            let active_trap = &traps[active_trap_index];
+            // Traps painted later sit above the active one, so focus may move into them.
+            let focus_allowed = traps[active_trap_index..]
+                .iter()
+                .any(|trap| trap.id.contains(focus, self));
+            if !focus_allowed {
+                refocus = Some(
+                    previous_focus_handle
+                        .clone()
+                        .filter(|handle| active_trap.contains(handle, self))
+                        .unwrap_or_else(|| active_trap.clone()),
+                );
+            }
+        }
+
+        if refocus.is_none() {
+            if let Some(focus) = self.focus {
+                for trap in traps {
+                    if trap.id.contains(focus, self) && !previous_focus_traps.contains(&trap.id) {
+                        if let Some(handle) = previous_focus_handle.as_ref() {
+                            self.focus_trap_restores
+                                .entry(trap.id)
+                                .or_insert_with(|| handle.downgrade());
+                        }
+                    }
+                }
+            }
+        }
+        self.focus_trap_restores
+            .retain(|trap_id, _| traps.iter().any(|trap| trap.id == *trap_id));
+        refocus
     }
 
     fn record_entities_accessed(&mut self, cx: &mut App) {
@@ -1886,7 +2161,7 @@ impl Window {
             tooltip_element = self.prepaint_tooltip(cx);
         }
 
-        self.mouse_hit_test = self.next_frame.hit_test(self.mouse_position);
+        self.mouse_hit_test = self.hit_test_at_mouse(&self.next_frame);
 
         // Now actually paint the elements.
         self.invalidator.set_phase(DrawPhase::Paint);
@@ -2054,11 +2329,16 @@ impl Window {
     }
 
     pub(crate) fn reuse_prepaint(&mut self, range: Range<PrepaintStateIndex>) {
-        self.next_frame.hitboxes.extend(
-            self.rendered_frame.hitboxes[range.start.hitboxes_index..range.end.hitboxes_index]
+        let reused_hitboxes =
+            &self.rendered_frame.hitboxes[range.start.hitboxes_index..range.end.hitboxes_index];
+        if self.next_frame.captured_hitbox.is_none()
+            && reused_hitboxes
                 .iter()
-                .cloned(),
-        );
+                .any(|hitbox| Some(hitbox.id) == self.rendered_frame.captured_hitbox)
+        {
+            self.next_frame.captured_hitbox = self.rendered_frame.captured_hitbox;
+        }
+        self.next_frame.hitboxes.extend(reused_hitboxes.iter().cloned());
         self.next_frame.tooltip_requests.extend(
             self.rendered_frame.tooltip_requests
                 [range.start.tooltips_index..range.end.tooltips_index]
@@ -2108,6 +2388,8 @@ impl Window {
             mouse_listeners_index: self.next_frame.mouse_listeners.len(),
             input_handlers_index: self.next_frame.input_handlers.len(),
             cursor_styles_index: self.next_frame.cursor_styles.len(),
+            tab_stops_index: self.next_frame.tab_stops.len(),
+            focus_traps_index: self.next_frame.focus_traps.len(),
             accessed_element_states_index: self.next_frame.accessed_element_states.len(),
             line_layout_index: self.text_system.layout_index(),
         }
@@ -2120,6 +2402,17 @@ impl Window {
                 .iter()
                 .cloned(),
         );
+        self.next_frame.tab_stops.extend(
+            self.rendered_frame.tab_stops[range.start.tab_stops_index..range.end.tab_stops_index]
+                .iter()
+                .cloned(),
+        );
+        self.next_frame.focus_traps.extend(
+            self.rendered_frame.focus_traps
+                [range.start.focus_traps_index..range.end.focus_traps_index]
+                .iter()
+                .cloned(),
+        );
         self.next_frame.input_handlers.extend(
             self.rendered_frame.input_handlers
                 [range.start.input_handlers_index..range.end.input_handlers_index]
@@ -2187,6 +2480,42 @@ impl Window {
         })
     }
 
+    /// Registers the given focus handle for Tab navigation. Handles are visited in ascending
+    /// `index` order, and in paint order among equal indices. Negative indices are skipped.
+    /// This method should only be called during the paint phase of element drawing.
+    pub fn insert_tab_stop(&mut self, index: isize, focus_handle: &FocusHandle) {
+        self.invalidator.debug_assert_paint();
+        if index >= 0 {
+            self.next_frame.tab_stops.push((index, focus_handle.clone()));
+        }
+    }
+
+    /// Confines Tab navigation to the tab stops inside the given focus handle's element while
+    /// it contains focus. When traps are nested, the innermost one containing focus applies.
+    /// Focus moved elsewhere while the trap holds it is redirected back into the trap, and once
+    /// the trap unmounts, focus returns to the handle that was focused before the trap took it.
+    /// This method should only be called during the paint phase of element drawing.
+    pub fn insert_focus_trap(&mut self, focus_handle: &FocusHandle) {
+        self.invalidator.debug_assert_paint();
+        self.next_frame.focus_traps.push(focus_handle.clone());
+    }
+
+    /// Focuses the given handle once the current frame is drawn, provided its element was not
+    /// part of the previous frame. The last request painted wins, and the request is dropped if
+    /// focus changes while the frame is drawn. This method should only be called during the
+    /// paint phase of element drawing.
+    pub fn request_mount_focus(&mut self, focus_handle: &FocusHandle) {
+        self.invalidator.debug_assert_paint();
+        if self
+            .rendered_frame
+            .dispatch_tree
+            .focusable_node_id(focus_handle.id)
+            .is_none()
+        {
+            self.next_frame.mount_focus = Some(focus_handle.clone());
+        }
+    }
+
     /// Sets a tooltip to be rendered for the upcoming frame. This method should only be called
     /// during the paint phase of element drawing.
     pub fn set_tooltip(&mut self, tooltip: AnyTooltip) -> TooltipId {
@@ -3051,11 +3380,32 @@ impl Window {
             bounds,
             content_mask,
             behavior,
+            subtree_len: 0,
         };
         self.next_frame.hitboxes.push(hitbox.clone());
+        // Hitbox ids change every frame, so move the capture to the capturing element's new hitbox.
+        if self.next_frame.captured_hitbox.is_none()
+            && self
+                .pointer_capture
+                .as_ref()
+                .is_some_and(|capture| capture.element_id.0 == self.element_id_stack)
+        {
+            self.next_frame.captured_hitbox = Some(id);
+        }
         hitbox
     }
 
+    /// Records that the hitboxes inserted since `first_hitbox_index` belong to the element whose
+    /// prepaint started there. Elements nested in it finish first, so they keep their own claim.
+    pub(crate) fn end_hitbox_subtree(&mut self, first_hitbox_index: usize) {
+        let len = self.next_frame.hitboxes.len() - first_hitbox_index;
+        if let Some(hitbox) = self.next_frame.hitboxes.get_mut(first_hitbox_index) {
+            if hitbox.subtree_len == 0 {
+                hitbox.subtree_len = len;
+            }
+        }
+    }
+
     /// Set a hitbox which will act as a control area of the platform window.
     ///
     /// This method should only be called as part of the paint phase of element drawing.
@@ -3322,6 +3672,7 @@ impl Window {
         cx.propagate_event = true;
         // Handlers may set this to true by calling `prevent_default`.
         self.default_prevented = false;
+        self.consumed_scroll_axes = Point::default();
 
         let event = match event {
             // Track the mouse position with our own state, since accessing the platform
@@ -3412,8 +3763,34 @@ impl Window {
         }
     }
 
+    // The last known position lies inside the window after the pointer exits, so nothing may be
+    // treated as hovered until the next mouse event arrives from within the window.
+    fn hit_test_at_mouse(&self, frame: &Frame) -> HitTest {
+        let mut hit_test = if self.mouse_exited {
+            HitTest::default()
+        } else {
+            frame.hit_test(self.mouse_position)
+        };
+        if let Some(captured_hitbox) = frame.captured_hitbox {
+            hit_test.captured_hover = Some(frame.captured_hover(captured_hitbox, &hit_test));
+        }
+        hit_test
+    }
+
     fn dispatch_mouse_event(&mut self, event: &dyn Any, cx: &mut App) {
-        let hit_test = self.rendered_frame.hit_test(self.mouse_position());
+        self.mouse_exited = event.is::<MouseExitEvent>();
+        // Pressing the captured button again means its release was never reported, e.g. because
+        // it happened outside the window, so the old capture is stale.
+        if let Some(event) = event.downcast_ref::<crate::MouseDownEvent>() {
+            if self
+                .pointer_capture
+                .as_ref()
+                .is_some_and(|capture| capture.button == event.button)
+            {
+                self.release_pointer();
+            }
+        }
+        let hit_test = self.hit_test_at_mouse(&self.rendered_frame);
         if hit_test != self.mouse_hit_test {
             self.mouse_hit_test = hit_test;
             self.reset_cursor_style(cx);
@@ -3451,6 +3828,16 @@ impl Window {
 
         self.rendered_frame.mouse_listeners = mouse_listeners;
 
+        if let Some(event) = event.downcast_ref::<MouseUpEvent>() {
+            if self
+                .pointer_capture
+                .as_ref()
+                .is_some_and(|capture| capture.button == event.button)
+            {
+                self.release_pointer();
+            }
+        }
+
         if cx.has_active_drag() {
             if event.is::<MouseMoveEvent>() {
                 // If this was a mouse move event, redraw the window so that the
@@ -3592,6 +3979,19 @@ impl Window {
             return;
         }
 
+        // Tab traversal only applies when no binding or key listener handled the keystroke.
+        if let Some(key_down) = event.downcast_ref::<KeyDownEvent>() {
+            let keystroke = &key_down.keystroke;
+            let modifiers = keystroke.modifiers;
+            if keystroke.key == "tab"
+                && !(modifiers.control || modifiers.alt || modifiers.platform || modifiers.function)
+                && self.focus_tab_stop(!modifiers.shift)
+            {
+                cx.propagate_event = false;
+                return;
+            }
+        }
+
         self.dispatch_modifiers_changed_event(event, &dispatch_path, cx);
         if !cx.propagate_event {
             return;
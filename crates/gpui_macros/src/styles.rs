@@ -151,6 +151,29 @@ pub fn overflow_style_methods(input: TokenStream) -> TokenStream {
             self.style().overflow.y = Some(gpui::Overflow::Hidden);
             self
         }
+
+        /// Sets the behavior of content that overflows the container to be clipped, without
+        /// letting the element shrink below the size of its content.
+        /// [Docs](https://tailwindcss.com/docs/overflow#clipping-content-that-overflows)
+        #visibility fn overflow_clip(mut self) -> Self {
+            self.style().overflow.x = Some(gpui::Overflow::Clip);
+            self.style().overflow.y = Some(gpui::Overflow::Clip);
+            self
+        }
+
+        /// Sets the behavior of content that overflows the container on the X axis to be clipped.
+        /// [Docs](https://tailwindcss.com/docs/overflow#clipping-content-that-overflows)
+        #visibility fn overflow_x_clip(mut self) -> Self {
+            self.style().overflow.x = Some(gpui::Overflow::Clip);
+            self
+        }
+
+        /// Sets the behavior of content that overflows the container on the Y axis to be clipped.
+        /// [Docs](https://tailwindcss.com/docs/overflow#clipping-content-that-overflows)
+        #visibility fn overflow_y_clip(mut self) -> Self {
+            self.style().overflow.y = Some(gpui::Overflow::Clip);
+            self
+        }
     };
 
     output.into()
@@ -6,6 +6,7 @@ use std::{
     collections::hash_map,
     path::{Path, PathBuf},
     sync::Arc,
+    time::Instant,
 };
 
 use anyhow::Result;
@@ -35,6 +36,7 @@ use crate::{task_store::TaskSettingsLocation, worktree_store::WorktreeStore};
 pub struct Inventory {
     fs: Arc<dyn Fs>,
     last_scheduled_tasks: VecDeque<(TaskSourceKind, ResolvedTask)>,
+    last_scheduled_at: HashMap<TaskId, Instant>,
     last_scheduled_scenarios: VecDeque<DebugScenario>,
     templates_from_settings: InventoryFor<TaskTemplate>,
     scenarios_from_settings: InventoryFor<DebugScenario>,
@@ -44,6 +46,7 @@ impl std::fmt::Debug for Inventory {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Inventory")
             .field("last_scheduled_tasks", &self.last_scheduled_tasks)
+            .field("last_scheduled_at", &self.last_scheduled_at)
             .field("last_scheduled_scenarios", &self.last_scheduled_scenarios)
             .field("templates_from_settings", &self.templates_from_settings)
             .field("scenarios_from_settings", &self.scenarios_from_settings)
@@ -239,6 +242,7 @@ impl Inventory {
         cx.new(|_| Self {
             fs,
             last_scheduled_tasks: VecDeque::default(),
+            last_scheduled_at: HashMap::default(),
             last_scheduled_scenarios: VecDeque::default(),
             templates_from_settings: InventoryFor::default(),
             scenarios_from_settings: InventoryFor::default(),
@@ -577,17 +581,33 @@ impl Inventory {
         task_source_kind: TaskSourceKind,
         resolved_task: ResolvedTask,
     ) {
+        self.last_scheduled_at
+            .insert(resolved_task.id.clone(), Instant::now());
         self.last_scheduled_tasks
             .push_back((task_source_kind, resolved_task));
         if self.last_scheduled_tasks.len() > 5_000 {
-            self.last_scheduled_tasks.pop_front();
+            if let Some((_, evicted_task)) = self.last_scheduled_tasks.pop_front() {
+                if !self
+                    .last_scheduled_tasks
+                    .iter()
+                    .any(|(_, task)| task.id == evicted_task.id)
+                {
+                    self.last_scheduled_at.remove(&evicted_task.id);
+                }
+            }
         }
     }
 
+    /// Returns when a task with the given id was last scheduled during this session, if ever.
+    pub fn task_last_scheduled_at(&self, id: &TaskId) -> Option<Instant> {
+        self.last_scheduled_at.get(id).copied()
+    }
+
     /// Deletes a resolved task from history, using its id.
     /// A similar may still resurface in `used_and_current_resolved_tasks` when its [`TaskTemplate`] is resolved again.
     pub fn delete_previously_used(&mut self, id: &TaskId) {
         self.last_scheduled_tasks.retain(|(_, task)| &task.id != id);
+        self.last_scheduled_at.remove(id);
     }
 
     fn global_templates_from_settings(
@@ -33,6 +33,7 @@ pub struct TaskTemplate {
     #[serde(default)]
     pub env: HashMap<String, String>,
     /// Current working directory to spawn the command into, defaults to current project root.
+    /// Relative paths are resolved against the task context's working directory.
     #[serde(default)]
     pub cwd: Option<String>,
     /// Whether to use a new terminal tab or reuse the existing one to spawn the process.
@@ -160,7 +161,13 @@ impl TaskTemplate {
                     &variable_names,
                     &mut substituted_variables,
                 )?;
-                Some(PathBuf::from(substituted_cwd))
+                let substituted_cwd = PathBuf::from(substituted_cwd);
+                match &cx.cwd {
+                    Some(context_cwd) if substituted_cwd.is_relative() => {
+                        Some(context_cwd.join(substituted_cwd))
+                    }
+                    _ => Some(substituted_cwd),
+                }
             }
             None => None,
         }
@@ -425,6 +432,8 @@ fn substitute_all_template_variables_in_map(
 mod tests {
     use std::{borrow::Cow, path::Path};
 
+    use util::path;
+
     use crate::{TaskVariables, VariableName};
 
     use super::*;
@@ -527,8 +536,17 @@ mod tests {
         };
         assert_eq!(
             resolved_task(&task_with_cwd, &cx).cwd,
-            Some(task_cwd),
-            "TaskTemplate's cwd should be taken on resolve if TaskContext's cwd is not None"
+            Some(context_cwd.join(&task_cwd)),
+            "Relative TaskTemplate's cwd should be resolved against TaskContext's cwd"
+        );
+
+        let absolute_task_cwd = PathBuf::from(path!("/d/e/f"));
+        let mut task_with_absolute_cwd = task_without_cwd.clone();
+        task_with_absolute_cwd.cwd = Some(absolute_task_cwd.display().to_string());
+        assert_eq!(
+            resolved_task(&task_with_absolute_cwd, &cx).cwd,
+            Some(absolute_task_cwd),
+            "Absolute TaskTemplate's cwd should be taken on resolve even if TaskContext's cwd is not None"
         );
     }
 
@@ -1,17 +1,19 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use crate::TaskContexts;
 use editor::Editor;
 use fuzzy::{StringMatch, StringMatchCandidate};
 use gpui::{
-    Action, AnyElement, App, AppContext as _, Context, DismissEvent, Entity, EventEmitter,
-    Focusable, InteractiveElement, ParentElement, Render, SharedString, Styled, Subscription, Task,
-    WeakEntity, Window, rems,
+    Action, AnyElement, App, AppContext as _, ClipboardItem, Context, DismissEvent, Entity,
+    EventEmitter, Focusable, InteractiveElement, ParentElement, Render, SharedString, Styled,
+    Subscription, Task, WeakEntity, Window, actions, rems,
 };
 use itertools::Itertools;
 use picker::{Picker, PickerDelegate, highlighted_match_with_paths::HighlightedMatch};
 use project::{TaskSourceKind, task_store::TaskStore};
-use task::{DebugScenario, ResolvedTask, RevealTarget, TaskContext, TaskTemplate};
+use task::{
+    DebugScenario, ResolvedTask, RevealTarget, SpawnInTerminal, TaskContext, TaskTemplate,
+};
 use ui::{
     ActiveTheme, Button, ButtonCommon, ButtonSize, Clickable, Color, FluentBuilder as _, Icon,
     IconButton, IconButtonShape, IconName, IconSize, IconWithIndicator, Indicator, IntoElement,
@@ -20,9 +22,11 @@ use ui::{
 };
 
 use util::{ResultExt, truncate_and_trailoff};
-use workspace::{ModalView, Workspace};
+use workspace::{ModalView, Toast, Workspace, notifications::NotificationId};
 pub use zed_actions::{Rerun, Spawn};
 
+actions!(tasks_modal, [CopyCommand]);
+
 /// A modal used to spawn new tasks.
 pub struct TasksModalDelegate {
     task_store: Entity<TaskStore>,
@@ -31,6 +35,7 @@ pub struct TasksModalDelegate {
     last_used_candidate_index: Option<usize>,
     divider_index: Option<usize>,
     matches: Vec<StringMatch>,
+    total_match_count: usize,
     selected_index: usize,
     workspace: WeakEntity<Workspace>,
     prompt: String,
@@ -65,6 +70,7 @@ impl TasksModalDelegate {
             workspace,
             candidates: None,
             matches: Vec::new(),
+            total_match_count: 0,
             last_used_candidate_index: None,
             divider_index: None,
             selected_index: 0,
@@ -121,6 +127,12 @@ impl TasksModalDelegate {
             })
         };
     }
+
+    fn selected_command_line(&self) -> Option<String> {
+        let hit = self.matches.get(self.selected_index)?;
+        let (_, task) = self.candidates.as_ref()?.get(hit.candidate_id)?;
+        command_line(&task.resolved)
+    }
 }
 
 pub struct TasksModal {
@@ -162,6 +174,27 @@ impl TasksModal {
         }
     }
 
+    fn copy_command(&mut self, _: &CopyCommand, _: &mut Window, cx: &mut Context<Self>) {
+        let picker = self.picker.read(cx);
+        let Some(command_line) = picker.delegate.selected_command_line() else {
+            return;
+        };
+        let workspace = picker.delegate.workspace.clone();
+        cx.write_to_clipboard(ClipboardItem::new_string(command_line));
+        workspace
+            .update(cx, |workspace, cx| {
+                workspace.show_toast(
+                    Toast::new(
+                        NotificationId::unique::<CopyCommand>(),
+                        "Copied task command to clipboard",
+                    )
+                    .autohide(),
+                    cx,
+                );
+            })
+            .ok();
+    }
+
     pub fn tasks_loaded(
         &mut self,
         task_contexts: Arc<TaskContexts>,
@@ -214,10 +247,11 @@ impl Render for TasksModal {
     fn render(
         &mut self,
         _window: &mut Window,
-        _: &mut Context<Self>,
+        cx: &mut Context<Self>,
     ) -> impl gpui::prelude::IntoElement {
         v_flex()
             .key_context("TasksModal")
+            .on_action(cx.listener(Self::copy_command))
             .w(rems(34.))
             .child(self.picker.clone())
     }
@@ -240,6 +274,7 @@ impl Focusable for TasksModal {
 impl ModalView for TasksModal {}
 
 const MAX_TAGS_LINE_LEN: usize = 30;
+const MAX_MATCHES: usize = 1000;
 
 impl PickerDelegate for TasksModalDelegate {
     type ListItem = ListItem;
@@ -354,19 +389,36 @@ impl PickerDelegate for TasksModalDelegate {
 
         cx.spawn_in(window, async move |picker, cx| {
             let candidates = candidates.await;
-            let matches = fuzzy::match_strings(
+            // Match against all candidates to know how many were left out by the limit.
+            let mut matches = fuzzy::match_strings(
                 &candidates,
                 &query,
                 true,
-                1000,
+                candidates.len(),
                 &Default::default(),
                 cx.background_executor().clone(),
             )
             .await;
+            let total_match_count = matches.len();
+            matches.truncate(MAX_MATCHES);
             picker
                 .update(cx, |picker, _| {
                     let delegate = &mut picker.delegate;
+                    if let Some(candidates) = delegate.candidates.as_ref() {
+                        for string_match in &mut matches {
+                            let Some((_, task)) = candidates.get(string_match.candidate_id) else {
+                                continue;
+                            };
+                            // Tags are only searchable, keep highlights on the displayed label.
+                            let label = task.display_label();
+                            string_match
+                                .positions
+                                .retain(|&position| position < label.len());
+                            string_match.string = label.to_string();
+                        }
+                    }
                     delegate.matches = matches;
+                    delegate.total_match_count = total_match_count;
                     if let Some(index) = delegate.last_used_candidate_index {
                         delegate.matches.sort_by_key(|m| m.candidate_id > index);
                     }
@@ -509,6 +561,20 @@ impl PickerDelegate for TasksModalDelegate {
             IconWithIndicator::new(icon, indicator)
                 .indicator_border_color(Some(cx.theme().colors().border_transparent))
         });
+        let last_run_label = self
+            .task_store
+            .read(cx)
+            .task_inventory()
+            .and_then(|inventory| {
+                inventory
+                    .read(cx)
+                    .task_last_scheduled_at(&resolved_task.id)
+            })
+            .map(|scheduled_at| {
+                Label::new(format_last_run(scheduled_at.elapsed()))
+                    .size(LabelSize::Small)
+                    .color(Color::Muted)
+            });
         let history_run_icon = if Some(ix) <= self.divider_index {
             Some(
                 Icon::new(IconName::HistoryRerun)
@@ -542,6 +608,7 @@ impl PickerDelegate for TasksModalDelegate {
                             MAX_TAGS_LINE_LEN,
                         )))
                         .flex_none()
+                        .children(last_run_label)
                         .child(history_run_icon.unwrap())
                         .into_any_element(),
                 )
@@ -681,6 +748,17 @@ impl PickerDelegate for TasksModalDelegate {
                         })
                         .unwrap_or_else(|| h_flex().into_any_element()),
                 )
+                .when(self.total_match_count > self.matches.len(), |this| {
+                    this.child(
+                        Label::new(format!(
+                            "Showing first {} of {} tasks",
+                            self.matches.len(),
+                            self.total_match_count
+                        ))
+                        .size(LabelSize::Small)
+                        .color(Color::Muted),
+                    )
+                })
                 .map(|this| {
                     if (current_modifiers.alt || self.matches.is_empty()) && !self.prompt.is_empty()
                     {
@@ -750,10 +828,47 @@ fn string_match_candidates<'a>(
     candidates
         .into_iter()
         .enumerate()
-        .map(|(index, (_, candidate))| StringMatchCandidate::new(index, candidate.display_label()))
+        .map(|(index, (_, candidate))| {
+            let tags = &candidate.original_task().tags;
+            if tags.is_empty() {
+                StringMatchCandidate::new(index, candidate.display_label())
+            } else {
+                let tags = tags.iter().map(|tag| format!("#{tag}")).join(" ");
+                StringMatchCandidate::new(
+                    index,
+                    &format!("{} {tags}", candidate.display_label()),
+                )
+            }
+        })
         .collect()
 }
 
+/// Builds a shell command line that reproduces the task, or `None` if its cwd cannot be quoted
+/// for a shell. The command itself is shell syntax already, so it is copied as it would run.
+fn command_line(task: &SpawnInTerminal) -> Option<String> {
+    match &task.cwd {
+        Some(cwd) => {
+            let cwd = cwd.to_string_lossy();
+            let cwd = shlex::try_quote(&cwd).ok()?;
+            Some(format!("cd {cwd} && {}", task.command_label))
+        }
+        None => Some(task.command_label.clone()),
+    }
+}
+
+fn format_last_run(elapsed: Duration) -> String {
+    let minutes = elapsed.as_secs() / 60;
+    if minutes == 0 {
+        "ran just now".to_string()
+    } else if minutes < 60 {
+        format!("ran {minutes}m ago")
+    } else if minutes < 60 * 24 {
+        format!("ran {}h ago", minutes / 60)
+    } else {
+        format!("ran {}d ago", minutes / (60 * 24))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{path::PathBuf, sync::Arc};
@@ -1291,6 +1406,97 @@ mod tests {
         cx.executor().run_until_parked()
     }
 
+    #[gpui::test]
+    async fn test_task_tags_are_searchable(cx: &mut TestAppContext) {
+        init_test(cx);
+        let fs = FakeFs::new(cx.executor());
+        fs.insert_tree(
+            path!("/dir"),
+            json!({
+                ".zed": {
+                    "tasks.json": r#"[
+                        {
+                            "label": "example task",
+                            "command": "echo",
+                            "args": ["4"],
+                            "tags": ["lint"]
+                        },
+                        {
+                            "label": "another one",
+                            "command": "echo",
+                            "args": ["55"]
+                        },
+                    ]"#,
+                },
+            }),
+        )
+        .await;
+
+        let project = Project::test(fs, [path!("/dir").as_ref()], cx).await;
+        let (workspace, cx) =
+            cx.add_window_view(|window, cx| Workspace::test_new(project, window, cx));
+
+        let tasks_picker = open_spawn_tasks(&workspace, cx);
+        cx.simulate_input("#lint");
+        assert_eq!(
+            task_names(&tasks_picker, cx),
+            vec!["example task"],
+            "A tag-only query should find the tagged task, displaying its label"
+        );
+        tasks_picker.read_with(cx, |tasks_picker, _| {
+            assert_eq!(
+                tasks_picker.delegate.matches[0].positions,
+                Vec::<usize>::new(),
+                "Tag matches should not be highlighted in the label"
+            );
+        });
+    }
+
+    #[gpui::test]
+    async fn test_copy_task_command(cx: &mut TestAppContext) {
+        init_test(cx);
+        let fs = FakeFs::new(cx.executor());
+        fs.insert_tree(
+            path!("/dir"),
+            json!({
+                ".zed": {
+                    "tasks.json": r#"[
+                        {
+                            "label": "example task",
+                            "command": "echo",
+                            "args": ["4", "&&", "ls", "$ZED_WORKTREE_ROOT"]
+                        },
+                    ]"#,
+                },
+            }),
+        )
+        .await;
+
+        let project = Project::test(fs, [path!("/dir").as_ref()], cx).await;
+        let (workspace, cx) =
+            cx.add_window_view(|window, cx| Workspace::test_new(project, window, cx));
+
+        let tasks_picker = open_spawn_tasks(&workspace, cx);
+        assert_eq!(task_names(&tasks_picker, cx), vec!["example task"]);
+
+        cx.dispatch_action(CopyCommand);
+        assert_eq!(
+            cx.read_from_clipboard().and_then(|item| item.text()),
+            Some(format!(
+                "cd {} && echo 4 && ls {}",
+                shlex::try_quote(path!("/dir")).unwrap(),
+                path!("/dir")
+            )),
+            "The selected task's command should be copied as it runs, with variables substituted"
+        );
+        workspace.update(cx, |workspace, cx| {
+            assert!(
+                workspace.active_modal::<TasksModal>(cx).is_some(),
+                "Copying the command should keep the modal open"
+            );
+        });
+    }
+
     fn open_spawn_tasks(
         workspace: &Entity<Workspace>,
         cx: &mut VisualTestContext,
@@ -1326,4 +1532,15 @@ mod tests {
                 .collect::<Vec<_>>()
         })
     }
+
+    #[test]
+    fn test_format_last_run() {
+        assert_eq!(format_last_run(Duration::from_secs(59)), "ran just now");
+        assert_eq!(format_last_run(Duration::from_secs(3 * 60)), "ran 3m ago");
+        assert_eq!(format_last_run(Duration::from_secs(2 * 60 * 60)), "ran 2h ago");
+        assert_eq!(
+            format_last_run(Duration::from_secs(5 * 24 * 60 * 60)),
+            "ran 5d ago"
+        );
+    }
 }
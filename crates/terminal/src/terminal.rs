@@ -48,7 +48,7 @@ use terminal_hyperlinks::RegexSearches;
 use terminal_settings::{AlternateScroll, CursorShape, TerminalSettings};
 use theme::{ActiveTheme, Theme};
 use urlencoding;
-use util::{paths::home_dir, truncate_and_trailoff};
+use util::{ResultExt, paths::home_dir, truncate_and_trailoff};
 
 use std::{
     borrow::Cow,
@@ -463,6 +463,7 @@ impl TerminalBuilder {
 
         let terminal = Terminal {
             task,
+            paused_task_process_group: None,
             pty_tx: Notifier(pty_tx),
             completion_tx,
             term,
@@ -639,6 +640,7 @@ pub struct Terminal {
     selection_phase: SelectionPhase,
     hyperlink_regex_searches: RegexSearches,
     task: Option<TaskState>,
+    paused_task_process_group: Option<u32>,
     vi_mode_enabled: bool,
     is_ssh_terminal: bool,
 }
@@ -664,17 +666,24 @@ pub enum TaskStatus {
     Unknown,
     /// The task is started and running currently.
     Running,
+    /// The task is started, but its process group is currently suspended.
+    Paused,
     /// After the start, the task stopped running and reported its error code back.
     Completed { success: bool },
 }
 
 impl TaskStatus {
     fn register_terminal_exit(&mut self) {
-        if self == &Self::Running {
+        if self.is_alive() {
             *self = Self::Unknown;
         }
     }
 
+    /// Whether the task's process has not exited yet, regardless of it being paused.
+    pub fn is_alive(&self) -> bool {
+        matches!(self, Self::Running | Self::Paused)
+    }
+
     fn register_task_exit(&mut self, error_code: i32) {
         *self = TaskStatus::Completed {
             success: error_code == 0,
@@ -1750,9 +1759,87 @@ impl Terminal {
         self.task.as_ref()
     }
 
+    /// Suspends the running task's foreground process group, leaving it alive until
+    /// [`Self::resume_task`] is called.
+    pub fn pause_task(&mut self, cx: &mut Context<Self>) -> Result<()> {
+        let Some(task) = self.task.as_mut() else {
+            bail!("terminal has no task to pause");
+        };
+        if task.status != TaskStatus::Running {
+            bail!("task is not running");
+        }
+        let Some(process_group) = self.pty_info.pid() else {
+            bail!("task process is not available");
+        };
+        let process_group = process_group.as_u32();
+        let shell = self.pty_info.pid_getter().fallback_pid();
+        // A shell with job control reports a stopped foreground job and moves on to the rest of
+        // the task's command, so keep the shell stopped for as long as its job is.
+        if process_group != shell {
+            signal_process_group(shell, ProcessGroupSignal::Stop)?;
+        }
+        if let Err(error) = signal_process_group(process_group, ProcessGroupSignal::Stop) {
+            if process_group != shell {
+                signal_process_group(shell, ProcessGroupSignal::Continue).log_err();
+            }
+            return Err(error);
+        }
+        self.paused_task_process_group = Some(process_group);
+        task.status = TaskStatus::Paused;
+        cx.notify();
+        Ok(())
+    }
+
+    /// Continues a task previously suspended with [`Self::pause_task`].
+    pub fn resume_task(&mut self, cx: &mut Context<Self>) -> Result<()> {
+        let Some(task) = self.task.as_mut() else {
+            bail!("terminal has no task to resume");
+        };
+        let (TaskStatus::Paused, Some(process_group)) =
+            (task.status, self.paused_task_process_group)
+        else {
+            bail!("task is not paused");
+        };
+        continue_task_process_groups(process_group, self.pty_info.pid_getter().fallback_pid())?;
+        self.paused_task_process_group = None;
+        task.status = TaskStatus::Running;
+        cx.notify();
+        Ok(())
+    }
+
+    /// Asks the task's foreground process group to terminate, waking it up first if it is paused.
+    /// The task status is updated once the process actually exits.
+    pub fn terminate_task(&mut self) -> Result<()> {
+        let Some(task) = self.task.as_ref() else {
+            bail!("terminal has no task to terminate");
+        };
+        if !task.status.is_alive() {
+            bail!("task has already finished");
+        }
+        let Some(process_group) = self
+            .paused_task_process_group
+            .or_else(|| self.pty_info.pid().map(|pid| pid.as_u32()))
+        else {
+            bail!("task process is not available");
+        };
+        let shell = self.pty_info.pid_getter().fallback_pid();
+        // Interactive shells ignore SIGTERM, so hang up the shell when it owns the foreground.
+        // It then hangs up its own jobs before exiting.
+        let signal = if process_group == shell {
+            ProcessGroupSignal::HangUp
+        } else {
+            ProcessGroupSignal::Terminate
+        };
+        signal_process_group(process_group, signal)?;
+        if task.status == TaskStatus::Paused {
+            continue_task_process_groups(process_group, shell)?;
+        }
+        Ok(())
+    }
+
     pub fn wait_for_completed_task(&self, cx: &App) -> Task<Option<ExitStatus>> {
         if let Some(task) = self.task() {
-            if task.status == TaskStatus::Running {
+            if task.status.is_alive() {
                 let completion_receiver = task.completion_rx.clone();
                 return cx.spawn(async move |_| completion_receiver.recv().await.ok().flatten());
             } else if let Ok(status) = task.completion_rx.try_recv() {
@@ -1784,7 +1871,7 @@ impl Terminal {
                 return;
             }
         };
-        if task.status != TaskStatus::Running {
+        if !task.status.is_alive() {
             return;
         }
         match error_code {
@@ -1840,6 +1927,44 @@ pub fn row_to_string(row: &Row<Cell>) -> String {
 }
 
 const TASK_DELIMITER: &str = "⏵ ";
+
+enum ProcessGroupSignal {
+    Stop,
+    Continue,
+    Terminate,
+    HangUp,
+}
+
+#[cfg(unix)]
+fn signal_process_group(process_group: u32, signal: ProcessGroupSignal) -> Result<()> {
+    let signal = match signal {
+        ProcessGroupSignal::Stop => libc::SIGSTOP,
+        ProcessGroupSignal::Continue => libc::SIGCONT,
+        ProcessGroupSignal::Terminate => libc::SIGTERM,
+        ProcessGroupSignal::HangUp => libc::SIGHUP,
+    };
+    // The task's shell spawns the command in its own foreground process group,
+    // so signal the whole group rather than only the shell process.
+    if unsafe { libc::killpg(process_group as libc::pid_t, signal) } != 0 {
+        return Err(std::io::Error::last_os_error().into());
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn signal_process_group(_: u32, _: ProcessGroupSignal) -> Result<()> {
+    bail!("signalling task processes is not supported on Windows")
+}
+
+/// Wakes up a paused task's job before its shell, so the shell never sees the job stopped.
+fn continue_task_process_groups(process_group: u32, shell: u32) -> Result<()> {
+    signal_process_group(process_group, ProcessGroupSignal::Continue)?;
+    if process_group != shell {
+        signal_process_group(shell, ProcessGroupSignal::Continue)?;
+    }
+    Ok(())
+}
+
 fn task_summary(task: &TaskState, error_code: Option<i32>) -> (bool, String, String) {
     let escaped_full_label = task.full_label.replace("\r\n", "\r").replace('\n', "\r");
     let (success, task_line) = match error_code {
@@ -2033,6 +2158,54 @@ mod tests {
         IndexedCell, TerminalBounds, TerminalContent, content_index_for_mouse, rgb_for_index,
     };
 
+    #[cfg(unix)]
+    #[test]
+    fn test_pause_and_resume_process_group() {
+        use std::{
+            os::unix::process::CommandExt as _,
+            process::Command,
+            thread,
+            time::{Duration, Instant},
+        };
+
+        use crate::{ProcessGroupSignal, signal_process_group};
+
+        let output_path =
+            std::env::temp_dir().join(format!("zed-terminal-pause-{}", std::process::id()));
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg("while true; do echo . >> \"$0\"; sleep 0.01; done")
+            .arg(&output_path)
+            .process_group(0)
+            .spawn()
+            .unwrap();
+        let process_group = child.id();
+        let output_len = || std::fs::metadata(&output_path).map_or(0, |metadata| metadata.len());
+
+        let started_at = Instant::now();
+        while output_len() == 0 {
+            assert!(started_at.elapsed() < Duration::from_secs(10), "process never counted");
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        signal_process_group(process_group, ProcessGroupSignal::Stop).unwrap();
+        thread::sleep(Duration::from_millis(50));
+        let paused_len = output_len();
+        thread::sleep(Duration::from_millis(200));
+        assert_eq!(output_len(), paused_len, "output should stall while paused");
+
+        signal_process_group(process_group, ProcessGroupSignal::Continue).unwrap();
+        let resumed_at = Instant::now();
+        while output_len() == paused_len {
+            assert!(resumed_at.elapsed() < Duration::from_secs(10), "process never resumed");
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        signal_process_group(process_group, ProcessGroupSignal::Terminate).unwrap();
+        child.wait().unwrap();
+        std::fs::remove_file(&output_path).unwrap();
+    }
+
     #[test]
     fn test_rgb_for_index() {
         // Test every possible value in the color cube.
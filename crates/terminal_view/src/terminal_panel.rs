@@ -33,11 +33,13 @@ use workspace::{
     ActivateNextPane, ActivatePane, ActivatePaneDown, ActivatePaneLeft, ActivatePaneRight,
     ActivatePaneUp, ActivatePreviousPane, DraggedSelection, DraggedTab, ItemId, MoveItemToPane,
     MoveItemToPaneInDirection, NewTerminal, Pane, PaneGroup, SplitDirection, SplitDown, SplitLeft,
-    SplitRight, SplitUp, SwapPaneDown, SwapPaneLeft, SwapPaneRight, SwapPaneUp, ToggleZoom,
+    SplitRight, SplitUp, SwapPaneDown, SwapPaneLeft, SwapPaneRight, SwapPaneUp, Toast, ToggleZoom,
     Workspace,
     dock::{DockPosition, Panel, PanelEvent, PanelHandle},
     item::SerializableItem,
-    move_active_item, move_item, pane,
+    move_active_item, move_item,
+    notifications::NotificationId,
+    pane,
     ui::IconName,
 };
 
@@ -46,13 +48,14 @@ use zed_actions::assistant::InlineAssist;
 
 const TERMINAL_PANEL_KEY: &str = "TerminalPanel";
 
-actions!(terminal_panel, [ToggleFocus]);
+actions!(terminal_panel, [ToggleFocus, CancelAllTasks]);
 
 pub fn init(cx: &mut App) {
     cx.observe_new(
         |workspace: &mut Workspace, _window, _: &mut Context<Workspace>| {
             workspace.register_action(TerminalPanel::new_terminal);
             workspace.register_action(TerminalPanel::open_terminal);
+            workspace.register_action(TerminalPanel::cancel_all_tasks);
             workspace.register_action(|workspace, _: &ToggleFocus, window, cx| {
                 if is_enabled_in_workspace(workspace, cx) {
                     workspace.toggle_panel_focus::<TerminalPanel>(window, cx);
@@ -594,6 +597,46 @@ impl TerminalPanel {
             .detach_and_log_err(cx);
     }
 
+    fn cancel_all_tasks(
+        workspace: &mut Workspace,
+        _: &CancelAllTasks,
+        _: &mut Window,
+        cx: &mut Context<Workspace>,
+    ) {
+        let Some(terminal_panel) = workspace.panel::<Self>(cx) else {
+            return;
+        };
+
+        let terminals = terminal_panel
+            .read(cx)
+            .center
+            .panes()
+            .into_iter()
+            .chain(workspace.panes())
+            .flat_map(|pane| {
+                pane.read(cx)
+                    .items()
+                    .filter_map(|item| item.act_as::<TerminalView>(cx))
+                    .map(|terminal_view| terminal_view.read(cx).terminal().clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let cancelled_count = terminate_running_tasks(terminals, cx);
+        if cancelled_count == 0 {
+            return;
+        }
+        let message = if cancelled_count == 1 {
+            "Cancelled 1 task".to_string()
+        } else {
+            format!("Cancelled {cancelled_count} tasks")
+        };
+        workspace.show_toast(
+            Toast::new(NotificationId::unique::<CancelAllTasks>(), message),
+            cx,
+        );
+    }
+
     fn terminals_for_task(
         &self,
         label: &str,
@@ -1140,6 +1183,29 @@ async fn wait_for_terminals_tasks(
     join_all(pending_tasks).await;
 }
 
+/// Terminates the task of every given terminal that is still running one, returning how many
+/// tasks were asked to stop.
+fn terminate_running_tasks(
+    terminals: impl IntoIterator<Item = Entity<Terminal>>,
+    cx: &mut App,
+) -> usize {
+    terminals
+        .into_iter()
+        .filter(|terminal| {
+            terminal
+                .read(cx)
+                .task()
+                .is_some_and(|task| task.status.is_alive())
+        })
+        .filter(|terminal| {
+            terminal
+                .update(cx, |terminal, _| terminal.terminate_task())
+                .log_err()
+                .is_some()
+        })
+        .count()
+}
+
 fn add_paths_to_terminal(
     pane: &mut Pane,
     paths: &[PathBuf],
@@ -1508,3 +1574,240 @@ impl Render for InlineAssistTabBarButton {
             })
     }
 }
+
+// Spawns real task processes through a pty.
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use gpui::{TestAppContext, WindowHandle};
+    use project::TaskSourceKind;
+    use std::{thread, time::Instant};
+    use task::{TaskContext, TaskTemplate};
+    use terminal::TaskStatus;
+    use workspace::{AppState, tasks::TaskSpawnFailed};
+
+    async fn init_test(cx: &mut TestAppContext) -> (Entity<Project>, WindowHandle<Workspace>) {
+        cx.executor().allow_parking();
+        let params = cx.update(AppState::test);
+        cx.update(|cx| {
+            terminal::init(cx);
+            theme::init(theme::LoadThemes::JustBase, cx);
+            editor::init_settings(cx);
+            Project::init_settings(cx);
+            language::init(cx);
+        });
+        let project = Project::test(params.fs.clone(), [], cx).await;
+        let window = cx.add_window(|window, cx| Workspace::test_new(project.clone(), window, cx));
+        (project, window)
+    }
+
+    fn spawn_task(
+        project: &Entity<Project>,
+        window: WindowHandle<Workspace>,
+        id: &str,
+        command: &str,
+        args: Vec<String>,
+        cx: &mut TestAppContext,
+    ) -> Entity<Terminal> {
+        project
+            .update(cx, |project, cx| {
+                project.create_terminal_with_venv(
+                    TerminalKind::Task(SpawnInTerminal {
+                        id: TaskId(id.to_string()),
+                        label: id.to_string(),
+                        command: command.to_string(),
+                        args,
+                        ..SpawnInTerminal::default()
+                    }),
+                    None,
+                    window.into(),
+                    cx,
+                )
+            })
+            .unwrap()
+    }
+
+    fn schedule_task_in_panel(
+        window: WindowHandle<Workspace>,
+        task: TaskTemplate,
+        cx: &mut TestAppContext,
+    ) {
+        window
+            .update(cx, |workspace, window, cx| {
+                let terminal_panel = cx.new(|cx| TerminalPanel::new(workspace, window, cx));
+                workspace.add_panel(terminal_panel.clone(), window, cx);
+                workspace.set_terminal_provider(TerminalProvider(terminal_panel));
+                workspace.schedule_task(
+                    TaskSourceKind::UserInput,
+                    &task,
+                    &TaskContext::default(),
+                    true,
+                    window,
+                    cx,
+                );
+            })
+            .unwrap();
+    }
+
+    fn wait_for_spawn_failure_toast(window: WindowHandle<Workspace>, cx: &mut TestAppContext) {
+        let started_at = Instant::now();
+        loop {
+            cx.run_until_parked();
+            let shown = window
+                .read_with(cx, |workspace, _| {
+                    workspace
+                        .notification_ids()
+                        .contains(&NotificationId::unique::<TaskSpawnFailed>())
+                })
+                .unwrap();
+            if shown {
+                return;
+            }
+            assert!(
+                started_at.elapsed() < Duration::from_secs(10),
+                "a task that fails to start should report the failure in a toast"
+            );
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[gpui::test]
+    async fn test_terminate_running_tasks(cx: &mut TestAppContext) {
+        let (project, window) = init_test(cx).await;
+        let terminals = (0..3)
+            .map(|ix| {
+                spawn_task(
+                    &project,
+                    window,
+                    &format!("sleep-{ix}"),
+                    "sleep",
+                    vec!["30".to_string()],
+                    cx,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let cancelled_count = cx.update(|cx| terminate_running_tasks(terminals.clone(), cx));
+        assert_eq!(cancelled_count, 3);
+
+        for terminal in &terminals {
+            terminal
+                .read_with(cx, |terminal, cx| terminal.wait_for_completed_task(cx))
+                .await;
+            terminal.read_with(cx, |terminal, _| {
+                assert!(terminal.task().is_some_and(|task| !task.status.is_alive()));
+            });
+        }
+
+        let cancelled_count = cx.update(|cx| terminate_running_tasks(terminals, cx));
+        assert_eq!(cancelled_count, 0);
+    }
+
+    #[gpui::test]
+    async fn test_pause_resume_and_terminate_task(cx: &mut TestAppContext) {
+        let (project, window) = init_test(cx).await;
+        let output_path =
+            std::env::temp_dir().join(format!("zed-task-pause-{}", std::process::id()));
+        let output_len = || std::fs::metadata(&output_path).map_or(0, |metadata| metadata.len());
+        let wait_for_output_after = |len: u64| {
+            let started_at = Instant::now();
+            while output_len() <= len {
+                assert!(started_at.elapsed() < Duration::from_secs(10), "task never counted");
+                thread::sleep(Duration::from_millis(10));
+            }
+        };
+        let assert_output_stalls = || {
+            thread::sleep(Duration::from_millis(100));
+            let paused_len = output_len();
+            thread::sleep(Duration::from_millis(300));
+            assert_eq!(output_len(), paused_len, "output should stall while paused");
+            paused_len
+        };
+        // The trailing `&& true` keeps the task's shell from exec-ing the counter, so the counter
+        // runs as a separate job that the shell is watching.
+        let terminal = spawn_task(
+            &project,
+            window,
+            "count",
+            "sh",
+            vec![
+                "-c".to_string(),
+                format!(
+                    "'while true; do echo . >> \"{}\"; sleep 0.05; done'",
+                    output_path.display()
+                ),
+                "&&".to_string(),
+                "true".to_string(),
+            ],
+            cx,
+        );
+        let task_status = |cx: &mut TestAppContext| {
+            cx.run_until_parked();
+            terminal.read_with(cx, |terminal, _| terminal.task().map(|task| task.status))
+        };
+        wait_for_output_after(0);
+
+        terminal
+            .update(cx, |terminal, cx| terminal.pause_task(cx))
+            .unwrap();
+        let paused_len = assert_output_stalls();
+        assert_eq!(task_status(cx), Some(TaskStatus::Paused));
+
+        terminal
+            .update(cx, |terminal, cx| terminal.resume_task(cx))
+            .unwrap();
+        wait_for_output_after(paused_len);
+        assert_eq!(task_status(cx), Some(TaskStatus::Running));
+
+        terminal
+            .update(cx, |terminal, cx| terminal.pause_task(cx))
+            .unwrap();
+        assert_output_stalls();
+        assert_eq!(task_status(cx), Some(TaskStatus::Paused));
+
+        terminal
+            .update(cx, |terminal, _| terminal.terminate_task())
+            .unwrap();
+        terminal
+            .read_with(cx, |terminal, cx| terminal.wait_for_completed_task(cx))
+            .await;
+        assert_eq!(
+            task_status(cx),
+            Some(TaskStatus::Completed { success: false })
+        );
+        std::fs::remove_file(&output_path).unwrap();
+    }
+
+    #[gpui::test]
+    async fn test_missing_task_command_shows_toast(cx: &mut TestAppContext) {
+        let (_, window) = init_test(cx).await;
+        schedule_task_in_panel(
+            window,
+            TaskTemplate {
+                label: "missing binary".to_string(),
+                command: "zed-test-nonexistent-binary".to_string(),
+                ..TaskTemplate::default()
+            },
+            cx,
+        );
+        wait_for_spawn_failure_toast(window, cx);
+    }
+
+    #[gpui::test]
+    async fn test_missing_task_cwd_shows_toast(cx: &mut TestAppContext) {
+        let (_, window) = init_test(cx).await;
+        let missing_dir =
+            std::env::temp_dir().join(format!("zed-task-missing-cwd-{}", std::process::id()));
+        schedule_task_in_panel(
+            window,
+            TaskTemplate {
+                label: "missing cwd".to_string(),
+                command: "true".to_string(),
+                cwd: Some(missing_dir.to_string_lossy().into_owned()),
+                ..TaskTemplate::default()
+            },
+            cx,
+        );
+        wait_for_spawn_failure_toast(window, cx);
+    }
+}
@@ -76,7 +76,7 @@ pub struct SendText(String);
 #[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq)]
 pub struct SendKeystroke(String);
 
-actions!(terminal, [RerunTask]);
+actions!(terminal, [RerunTask, PauseTask, ResumeTask]);
 
 impl_actions!(terminal, [SendText, SendKeystroke]);
 
@@ -379,6 +379,7 @@ impl TerminalView {
             .map_or(false, |terminal_panel| {
                 terminal_panel.read(cx).assistant_enabled()
             });
+        let task_status = self.terminal.read(cx).task().map(|task| task.status);
         let context_menu = ContextMenu::build(window, cx, |menu, _, _| {
             menu.context(self.focus_handle.clone())
                 .action("New Terminal", Box::new(NewTerminal))
@@ -391,6 +392,12 @@ impl TerminalView {
                     menu.separator()
                         .action("Inline Assist", Box::new(InlineAssist::default()))
                 })
+                .when(task_status == Some(TaskStatus::Running), |menu| {
+                    menu.separator().action("Pause Task", Box::new(PauseTask))
+                })
+                .when(task_status == Some(TaskStatus::Paused), |menu| {
+                    menu.separator().action("Resume Task", Box::new(ResumeTask))
+                })
                 .separator()
                 .action(
                     "Close Terminal Tab",
@@ -474,6 +481,18 @@ impl TerminalView {
         window.dispatch_action(Box::new(task), cx);
     }
 
+    fn pause_task(&mut self, _: &PauseTask, _: &mut Window, cx: &mut Context<Self>) {
+        self.terminal
+            .update(cx, |terminal, cx| terminal.pause_task(cx))
+            .log_err();
+    }
+
+    fn resume_task(&mut self, _: &ResumeTask, _: &mut Window, cx: &mut Context<Self>) {
+        self.terminal
+            .update(cx, |terminal, cx| terminal.resume_task(cx))
+            .log_err();
+    }
+
     fn clear(&mut self, _: &Clear, _: &mut Window, cx: &mut Context<Self>) {
         self.scroll_top = px(0.);
         self.terminal.update(cx, |term, _| term.clear());
@@ -1510,6 +1529,8 @@ impl Render for TerminalView {
             .on_action(cx.listener(TerminalView::show_character_palette))
             .on_action(cx.listener(TerminalView::select_all))
             .on_action(cx.listener(TerminalView::rerun_task))
+            .on_action(cx.listener(TerminalView::pause_task))
+            .on_action(cx.listener(TerminalView::resume_task))
             .on_key_down(cx.listener(Self::key_down))
             .on_mouse_down(
                 MouseButton::Right,
@@ -1588,6 +1609,11 @@ impl Item for TerminalView {
                     Color::Disabled,
                     TerminalView::rerun_button(&terminal_task),
                 ),
+                TaskStatus::Paused => (
+                    IconName::DebugPause,
+                    Color::Warning,
+                    TerminalView::rerun_button(&terminal_task),
+                ),
                 TaskStatus::Unknown => (
                     IconName::Warning,
                     Color::Warning,
@@ -1680,7 +1706,7 @@ impl Item for TerminalView {
 
     fn is_dirty(&self, cx: &gpui::App) -> bool {
         match self.terminal.read(cx).task() {
-            Some(task) => task.status == TaskStatus::Running,
+            Some(task) => task.status.is_alive(),
             None => self.has_bell(),
         }
     }
@@ -232,7 +232,9 @@ impl Element for Scrollbar {
         _: &mut App,
     ) -> Self::PrepaintState {
         window.with_content_mask(Some(ContentMask { bounds }), |window| {
-            window.insert_hitbox(bounds, HitboxBehavior::Normal)
+            // Block mouse events from reaching content painted underneath the bar, while still
+            // letting scroll wheel events through to the scroll container.
+            window.insert_hitbox(bounds, HitboxBehavior::BlockMouseExceptScroll)
         })
     }
 
@@ -409,3 +411,65 @@ impl IntoElement for Scrollbar {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::{Modifiers, MouseButton, TestAppContext, point};
+    use settings::SettingsStore;
+
+    struct ScrollbarOverContentView {
+        scroll_handle: ScrollHandle,
+        scrollbar_state: ScrollbarState,
+        content_clicks: Rc<Cell<usize>>,
+    }
+
+    impl Render for ScrollbarOverContentView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let content_clicks = self.content_clicks.clone();
+            div()
+                .relative()
+                .size(px(100.))
+                .child(
+                    div()
+                        .id("content")
+                        .size_full()
+                        .overflow_y_scroll()
+                        .track_scroll(&self.scroll_handle)
+                        .on_click(move |_, _, _| content_clicks.set(content_clicks.get() + 1))
+                        .child(div().h(px(200.))),
+                )
+                .children(Scrollbar::vertical(self.scrollbar_state.clone()).map(|scrollbar| {
+                    div().absolute().top_0().right_0().h_full().child(scrollbar)
+                }))
+        }
+    }
+
+    #[gpui::test]
+    fn test_thumb_click_does_not_reach_content(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let settings_store = SettingsStore::test(cx);
+            cx.set_global(settings_store);
+            theme::init(theme::LoadThemes::JustBase, cx);
+        });
+        let scroll_handle = ScrollHandle::new();
+        let scrollbar_state = ScrollbarState::new(scroll_handle.clone());
+        let content_clicks = Rc::new(Cell::new(0));
+        let (view, cx) = cx.add_window_view(|_, _| ScrollbarOverContentView {
+            scroll_handle,
+            scrollbar_state: scrollbar_state.clone(),
+            content_clicks: content_clicks.clone(),
+        });
+        // The scrollbar only appears once the content has been measured.
+        view.update(cx, |_, cx| cx.notify());
+        cx.run_until_parked();
+
+        cx.simulate_click(point(px(50.), px(20.)), Modifiers::none());
+        assert_eq!(content_clicks.get(), 1);
+
+        cx.simulate_mouse_down(point(px(94.), px(20.)), MouseButton::Left, Modifiers::none());
+        assert!(scrollbar_state.is_dragging());
+        cx.simulate_mouse_up(point(px(94.), px(20.)), MouseButton::Left, Modifiers::none());
+        assert_eq!(content_clicks.get(), 1);
+    }
+}
@@ -1,14 +1,20 @@
 use std::process::ExitStatus;
 
 use anyhow::Result;
-use gpui::{AppContext, Context, Entity, Task};
+use gpui::{Context, Entity, Task};
 use language::Buffer;
 use project::TaskSourceKind;
 use remote::ConnectionState;
 use task::{DebugScenario, ResolvedTask, SpawnInTerminal, TaskContext, TaskTemplate};
 use ui::Window;
 
-use crate::Workspace;
+use crate::{Toast, Workspace, notifications::NotificationId};
+
+/// Identifies the toast shown when a scheduled task fails to start.
+pub struct TaskSpawnFailed;
+
+/// The exit code POSIX shells use when the command they were asked to run cannot be found.
+const COMMAND_NOT_FOUND_EXIT_CODE: i32 = 127;
 
 impl Workspace {
     pub fn schedule_task(
@@ -72,18 +78,44 @@ impl Workspace {
         }
 
         if let Some(terminal_provider) = self.terminal_provider.as_ref() {
+            let task_label = spawn_in_terminal.label.clone();
             let task_status = terminal_provider.spawn(spawn_in_terminal, window, cx);
-            cx.background_spawn(async move {
-                match task_status.await {
+            cx.spawn(async move |workspace, cx| {
+                let failure = match task_status.await {
                     Some(Ok(status)) => {
                         if status.success() {
                             log::debug!("Task spawn succeeded");
+                            None
                         } else {
                             log::debug!("Task spawn failed, code: {:?}", status.code());
+                            // Tasks run through `$SHELL -i -c`, so a missing binary does not fail
+                            // the spawn itself: the shell starts and exits with this code instead.
+                            (status.code() == Some(COMMAND_NOT_FOUND_EXIT_CODE))
+                                .then(|| "command not found".to_string())
                         }
                     }
-                    Some(Err(e)) => log::error!("Task spawn failed: {e}"),
-                    None => log::debug!("Task spawn got cancelled"),
+                    Some(Err(e)) => {
+                        log::error!("Task spawn failed: {e}");
+                        Some(e.to_string())
+                    }
+                    None => {
+                        log::debug!("Task spawn got cancelled");
+                        None
+                    }
+                };
+                // A task that never started is easy to miss in a background terminal.
+                if let Some(failure) = failure {
+                    workspace
+                        .update(cx, |workspace, cx| {
+                            workspace.show_toast(
+                                Toast::new(
+                                    NotificationId::unique::<TaskSpawnFailed>(),
+                                    format!("Failed to start task '{task_label}': {failure}"),
+                                ),
+                                cx,
+                            );
+                        })
+                        .ok();
                 }
             })
             .detach();
@@ -116,3 +148,4 @@ impl Workspace {
         }
     }
 }
+